@@ -0,0 +1,460 @@
+// caepf.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::cpf::Cpf;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// Same as CNPJ; the second check digit includes the first one
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+/// An error which can be returned when parsing a [`Caepf`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCaepfError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseCaepfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseCaepfError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid CAEPF number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCaepfError {}
+
+/// A valid CAEPF (_Cadastro de Atividade Econômica da Pessoa Física_) number, which identifies an
+/// economic activity of a person, such as a rural employer. It consists of the base of the
+/// owner's [`Cpf`], a sequence of the activity and two check digits. Parsing recognizes numbers
+/// with or without separators (dot, slash and minus).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Caepf([u8; 14]);
+
+impl Caepf {
+    /// Parses a byte slice of numbers as a CAEPF, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Caepf;
+    ///
+    /// match Caepf::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 1, 0, 0]) {
+    ///     Ok(caepf) => println!("{caepf} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digits:
+    ///
+    /// ```rust
+    /// use brids::Caepf;
+    ///
+    /// match Caepf::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 1]) {
+    ///     Ok(caepf) => println!("{caepf} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseCaepfError> {
+        let mut numbers = [0; 14];
+        match slice.len() {
+            0 => return Err(ParseCaepfError::Empty),
+            len @ (12 | 14) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseCaepfError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseCaepfError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers[..9].iter().all(|&x| x == first_number) {
+            return Err(ParseCaepfError::InvalidNumber);
+        }
+
+        let check_digits = calc_check_digits(&numbers[..12]);
+        if slice.len() == 12 {
+            numbers[12..].copy_from_slice(&check_digits);
+        } else if check_digits != numbers[12..] {
+            return Err(ParseCaepfError::InvalidNumber);
+        }
+
+        Ok(Caepf(numbers))
+    }
+
+    /// Creates the CAEPF of an activity of the owner of the CPF. The sequence goes from `0` to
+    /// `999`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Caepf, Cpf};
+    ///
+    /// let cpf = "123.456.789-09".parse::<Cpf>().expect("invalid CPF");
+    /// let caepf = Caepf::from_cpf(cpf, 2).expect("invalid sequence");
+    /// assert_eq!("123.456.789/002-81", caepf.to_string());
+    /// ```
+    pub fn from_cpf(cpf: Cpf, sequence: u16) -> Result<Self, ParseCaepfError> {
+        if sequence > 999 {
+            return Err(ParseCaepfError::InvalidNumber);
+        }
+
+        let mut numbers = [0; 12];
+        numbers[..9].copy_from_slice(cpf.base());
+        numbers[9] = (sequence / 100) as u8;
+        numbers[10] = (sequence / 10 % 10) as u8;
+        numbers[11] = (sequence % 10) as u8;
+
+        Self::from_slice(&numbers)
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Caepf;
+    ///
+    /// let caepf = "123.456.789/001-00".parse::<Caepf>().expect("invalid CAEPF");
+    /// let digits = caepf.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 14] {
+        &self.0
+    }
+
+    /// Returns the CPF of the owner.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Caepf, Cpf};
+    ///
+    /// let caepf = "123.456.789/001-00".parse::<Caepf>().expect("invalid CAEPF");
+    /// assert_eq!(Ok(caepf.cpf()), "123.456.789-09".parse::<Cpf>());
+    /// ```
+    pub fn cpf(&self) -> Cpf {
+        match Cpf::from_slice(&self.0[..9]) {
+            Ok(cpf) => cpf,
+            Err(_) => unreachable!("CPF base already checked"),
+        }
+    }
+
+    /// Returns the sequence of the activity.
+    #[inline]
+    pub fn sequence(&self) -> u16 {
+        u16::from(self.0[9]) * 100 + u16::from(self.0[10]) * 10 + u16::from(self.0[11])
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Caepf;
+    ///
+    /// let caepf = Caepf::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Caepf {
+    const NAME: &'static str = "CAEPF";
+    const LEN: usize = 14;
+    const FORMATTED_LEN: usize = 18;
+
+    type Error = ParseCaepfError;
+    type CheckDigits = [u8; 2];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..12]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[12..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 12) {
+            return Err(ParseCaepfError::InvalidNumber);
+        }
+
+        Ok(calc_check_digits(base))
+    }
+}
+
+impl AsRef<[u8]> for Caepf {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Caepf> for [u8; 14] {
+    #[inline]
+    fn from(caepf: Caepf) -> [u8; 14] {
+        caepf.0
+    }
+}
+
+impl From<Caepf> for Cpf {
+    #[inline]
+    fn from(caepf: Caepf) -> Cpf {
+        caepf.cpf()
+    }
+}
+
+impl TryFrom<&[u8]> for Caepf {
+    type Error = ParseCaepfError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 14]> for Caepf {
+    type Error = ParseCaepfError;
+
+    #[inline]
+    fn try_from(value: &[u8; 14]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Caepf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Caepf(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Caepf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                3 | 6 => f.write_char('.')?,
+                9 => f.write_char('/')?,
+                12 => f.write_char('-')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Caepf {
+    type Err = ParseCaepfError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 14];
+
+        if s.is_empty() {
+            return Err(ParseCaepfError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 14 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseCaepfError::InvalidNumber);
+                    }
+                }
+                ('.', 3 | 7) | ('/', 11) | ('-', 15) => continue,
+                _ => return Err(ParseCaepfError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 14 {
+            return Err(ParseCaepfError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+fn calc_check_digits(base: &[u8]) -> [u8; 2] {
+    let mut numbers = [0; 13];
+    numbers[..12].copy_from_slice(base);
+    numbers[12] = CHECKSUM.check_digit(base);
+    let check_digits = numbers[12] * 10 + CHECKSUM.check_digit(&numbers);
+
+    // Adds 12, discarding the hundreds
+    let check_digits = (check_digits + 12) % 100;
+    [check_digits / 10, check_digits % 10]
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Caepf> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Caepf {
+        let cpf = rng.random::<Cpf>();
+        let sequence = rng.random_range(1..=999);
+        match Caepf::from_cpf(cpf, sequence) {
+            Ok(caepf) => caepf,
+            // Repeated numbers
+            Err(_) => self.sample(rng),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Caepf {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Caepf {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Caepf([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 1, 0, 0]);
+        let b = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 2, 8, 1];
+
+        assert_eq!(Ok(a), Caepf::from_slice(&a.0));
+        assert_eq!(Ok(a), Caepf::from_slice(&a.0[..12]));
+        assert!(Caepf::from_slice(&b).is_ok());
+        assert_eq!(
+            Caepf::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 1, 8, 8]),
+            Err(ParseCaepfError::InvalidNumber)
+        );
+        assert_eq!(
+            Caepf::from_slice(&[1, 1, 1, 1, 1, 1, 1, 1, 1, 0, 0, 1]),
+            Err(ParseCaepfError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn cpf() {
+        let cpf = "123.456.789-09".parse::<Cpf>().unwrap();
+        let a = Caepf::from_cpf(cpf, 1).unwrap();
+
+        assert_eq!(Caepf([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 1, 0, 0]), a);
+        assert_eq!(cpf, a.cpf());
+        assert_eq!(cpf, Cpf::from(a));
+        assert_eq!(1, a.sequence());
+        assert_eq!(999, Caepf::from_cpf(cpf, 999).unwrap().sequence());
+        assert_eq!(
+            Caepf::from_cpf(cpf, 1000),
+            Err(ParseCaepfError::InvalidNumber)
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Caepf::generate();
+        let b = a.to_string().parse::<Caepf>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Caepf("123.456.789/001-00")"#;
+        let b = Caepf([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0, 1, 0, 0]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "123.456.789/002-81".parse::<Caepf>().unwrap();
+        let b = "12345678900281".parse::<Caepf>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!("".parse::<Caepf>(), Err(ParseCaepfError::Empty));
+        assert_eq!(
+            "123-456-789/002-81".parse::<Caepf>(),
+            Err(ParseCaepfError::InvalidCharacter('-', 3))
+        );
+        assert_eq!(
+            "123.456.789/002-80".parse::<Caepf>(),
+            Err(ParseCaepfError::InvalidNumber)
+        );
+        assert_eq!(
+            "123.456.789/002-8".parse::<Caepf>(),
+            Err(ParseCaepfError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let caepf_str = "123.456.789/002-81";
+        let caepf = Caepf::from_str(caepf_str).unwrap();
+        serde_test::assert_tokens(&caepf, &[serde_test::Token::Str(caepf_str)]);
+    }
+}
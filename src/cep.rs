@@ -0,0 +1,411 @@
+// cep.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::uf::Uf;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// Ranges of the first 5 numbers assigned to each state by Correios
+const RANGES: [(u32, u32, Uf); 30] = [
+    (1000, 19999, Uf::Sp),
+    (20000, 28999, Uf::Rj),
+    (29000, 29999, Uf::Es),
+    (30000, 39999, Uf::Mg),
+    (40000, 48999, Uf::Ba),
+    (49000, 49999, Uf::Se),
+    (50000, 56999, Uf::Pe),
+    (57000, 57999, Uf::Al),
+    (58000, 58999, Uf::Pb),
+    (59000, 59999, Uf::Rn),
+    (60000, 63999, Uf::Ce),
+    (64000, 64999, Uf::Pi),
+    (65000, 65999, Uf::Ma),
+    (66000, 68899, Uf::Pa),
+    (68900, 68999, Uf::Ap),
+    (69000, 69299, Uf::Am),
+    (69300, 69399, Uf::Rr),
+    (69400, 69899, Uf::Am),
+    (69900, 69999, Uf::Ac),
+    (70000, 72799, Uf::Df),
+    (72800, 72999, Uf::Go),
+    (73000, 73699, Uf::Df),
+    (73700, 76799, Uf::Go),
+    (76800, 76999, Uf::Ro),
+    (77000, 77999, Uf::To),
+    (78000, 78899, Uf::Mt),
+    (79000, 79999, Uf::Ms),
+    (80000, 87999, Uf::Pr),
+    (88000, 89999, Uf::Sc),
+    (90000, 99999, Uf::Rs),
+];
+
+/// An error which can be returned when parsing a [`Cep`].
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCepError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseCepError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseCepError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid CEP"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCepError {}
+
+/// A CEP (postal code). Parsing recognizes codes with or without separators (dot and minus).
+///
+/// A CEP has no check digits, so any 8 numbers are parsed; use [`Cep::uf`] to check if the code
+/// belongs to a range assigned to a state.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cep([u8; 8]);
+
+impl Cep {
+    /// Parses a byte slice of numbers as a CEP.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cep;
+    ///
+    /// match Cep::from_slice(&[0, 1, 3, 1, 0, 9, 3, 0]) {
+    ///     Ok(cep) => println!("{cep} is a valid code."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseCepError> {
+        let mut numbers = [0; 8];
+        match slice.len() {
+            0 => return Err(ParseCepError::Empty),
+            8 => numbers.copy_from_slice(slice),
+            _ => return Err(ParseCepError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseCepError::InvalidNumber);
+        }
+
+        Ok(Cep(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cep;
+    ///
+    /// let cep = "01310-930".parse::<Cep>().expect("invalid CEP");
+    /// let digits = cep.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// Returns the state of the code, or `None` if the code is in an unassigned range.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cep, Uf};
+    ///
+    /// let cep = "01310-930".parse::<Cep>().expect("invalid CEP");
+    /// assert_eq!(Some(Uf::Sp), cep.uf());
+    ///
+    /// let cep = "00100-000".parse::<Cep>().expect("invalid CEP");
+    /// assert_eq!(None, cep.uf());
+    /// ```
+    pub fn uf(&self) -> Option<Uf> {
+        let prefix = self.0[..5]
+            .iter()
+            .fold(0, |acc, &x| acc * 10 + u32::from(x));
+
+        RANGES
+            .iter()
+            .find(|&&(start, end, _)| (start..=end).contains(&prefix))
+            .map(|&(_, _, uf)| uf)
+    }
+
+    /// Generates a random code in an assigned range, using [`rand::rng`] (requires `std` and
+    /// `rand` features). To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cep;
+    ///
+    /// let cep = Cep::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl AsRef<[u8]> for Cep {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Cep> for [u8; 8] {
+    #[inline]
+    fn from(cep: Cep) -> [u8; 8] {
+        cep.0
+    }
+}
+
+impl TryFrom<&[u8]> for Cep {
+    type Error = ParseCepError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 8]> for Cep {
+    type Error = ParseCepError;
+
+    #[inline]
+    fn try_from(value: &[u8; 8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Cep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cep(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Cep {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            if i == 5 {
+                f.write_char('-')?;
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cep {
+    type Err = ParseCepError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 8];
+
+        if s.is_empty() {
+            return Err(ParseCepError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        let mut has_dot = false;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 8 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseCepError::InvalidNumber);
+                    }
+                }
+                ('.', 2) => has_dot = true,
+                ('-', 6) if has_dot => continue,
+                ('-', 5) if !has_dot => continue,
+                _ => return Err(ParseCepError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 8 {
+            return Err(ParseCepError::InvalidNumber);
+        }
+
+        Ok(Cep(numbers))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Cep> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cep {
+        let mut numbers = [0; 8];
+        loop {
+            for number in &mut numbers {
+                *number = rng.random_range(0..=9);
+            }
+
+            let cep = Cep(numbers);
+            if cep.uf().is_some() {
+                return cep;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cep {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(not(feature = "std"))]
+        use crate::alloc::string::ToString;
+
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cep {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CepStringVisitor;
+
+        impl de::Visitor<'_> for CepStringVisitor {
+            type Value = Cep;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a CEP string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Cep, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Cep, E> {
+                Cep::from_slice(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CepStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Cep([0, 1, 3, 1, 0, 9, 3, 0]);
+        let b: [u8; 8] = [0, 1, 3, 1, 0, 9, 3, 0];
+
+        assert_eq!(a, Cep::from_slice(&b).unwrap());
+        assert_eq!(
+            Cep::from_slice(&[0, 1, 3, 1, 0, 9, 3]),
+            Err(ParseCepError::InvalidNumber)
+        );
+        assert_eq!(
+            Cep::from_slice(&[0, 1, 3, 1, 0, 9, 3, 10]),
+            Err(ParseCepError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn uf() {
+        let a = Cep([0, 1, 3, 1, 0, 9, 3, 0]);
+        let b = Cep([7, 0, 0, 4, 0, 0, 1, 0]);
+        let c = Cep([6, 9, 3, 0, 0, 0, 0, 0]);
+        let d = Cep([9, 9, 9, 9, 9, 9, 9, 9]);
+        let e = Cep([0, 0, 9, 9, 9, 9, 9, 9]);
+
+        assert_eq!(Some(Uf::Sp), a.uf());
+        assert_eq!(Some(Uf::Df), b.uf());
+        assert_eq!(Some(Uf::Rr), c.uf());
+        assert_eq!(Some(Uf::Rs), d.uf());
+        assert_eq!(None, e.uf());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Cep::generate();
+        let b = a.to_string().parse::<Cep>().unwrap();
+
+        assert_eq!(a, b);
+        assert!(a.uf().is_some());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Cep("01310-930")"#;
+        let b = Cep([0, 1, 3, 1, 0, 9, 3, 0]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = "01310-930";
+        let b = Cep([0, 1, 3, 1, 0, 9, 3, 0]);
+
+        assert_eq!(a, format!("{b}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "01310-930".parse::<Cep>().unwrap();
+        let b = "01.310-930".parse::<Cep>().unwrap();
+        let c = "01310930".parse::<Cep>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_eq!("".parse::<Cep>(), Err(ParseCepError::Empty));
+        assert_eq!(
+            "013-10930".parse::<Cep>(),
+            Err(ParseCepError::InvalidCharacter('-', 3))
+        );
+        assert_eq!("01310-93".parse::<Cep>(), Err(ParseCepError::InvalidNumber));
+        assert_eq!(
+            "01310-9300".parse::<Cep>(),
+            Err(ParseCepError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let cep_str = "01310-930";
+        let cep = Cep::from_str(cep_str).unwrap();
+        serde_test::assert_tokens(&cep, &[serde_test::Token::Str(cep_str)]);
+    }
+}
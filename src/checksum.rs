@@ -0,0 +1,404 @@
+// checksum.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+//! Check digit algorithms used by Brazilian identifiers.
+//!
+//! All functions operate on slices of numbers in the range `0..=9`, most significant first, as
+//! returned by [`Cpf::as_bytes`](crate::Cpf::as_bytes) and similar methods.
+//!
+//! # Examples
+//!
+//! Computing the first check digit of a CPF number:
+//!
+//! ```rust
+//! use brids::checksum::Mod11;
+//!
+//! const CPF: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+//!
+//! assert_eq!(0, CPF.check_digit(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+//! assert!(CPF.is_valid(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]));
+//! ```
+
+/// The value that represents the `X` check digit, used by some identifiers when the computed
+/// digit would be `10`.
+pub const X: u8 = 10;
+
+/// A weighted modulo 11 check digit algorithm.
+///
+/// The weights are applied from the rightmost number to the leftmost one, starting over from the
+/// first weight when the numbers outnumber them. For example, the CNPJ weights are simply
+/// `[2, 3, 4, 5, 6, 7, 8, 9]`.
+///
+/// By default, the check digit is `11` minus the remainder of the weighted sum, and the values
+/// `10` and `11` become `0`. Use the builder methods to change this behavior.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mod11<'a> {
+    weights: &'a [u8],
+    complement: bool,
+    ten: u8,
+    eleven: u8,
+}
+
+impl<'a> Mod11<'a> {
+    /// Creates a new algorithm with the weights, from the rightmost number to the leftmost one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty.
+    pub const fn new(weights: &'a [u8]) -> Self {
+        assert!(!weights.is_empty(), "weights must not be empty");
+        Self {
+            weights,
+            complement: true,
+            ten: 0,
+            eleven: 0,
+        }
+    }
+
+    /// Uses the remainder of the weighted sum as the check digit, instead of `11` minus the
+    /// remainder. In this mode, only the value `10` is mapped.
+    #[inline]
+    pub const fn remainder(mut self) -> Self {
+        self.complement = false;
+        self
+    }
+
+    /// Sets the check digit used when the computed value is `10`. Use [`X`] to represent the
+    /// `X` digit.
+    #[inline]
+    pub const fn map_ten(mut self, digit: u8) -> Self {
+        self.ten = digit;
+        self
+    }
+
+    /// Sets the check digit used when the computed value is `11`.
+    #[inline]
+    pub const fn map_eleven(mut self, digit: u8) -> Self {
+        self.eleven = digit;
+        self
+    }
+
+    /// Returns the weight applied to the number at `index`, in a slice of `len` numbers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than `len`.
+    #[inline]
+    pub fn weight(&self, index: usize, len: usize) -> u8 {
+        self.weights[(len - 1 - index) % self.weights.len()]
+    }
+
+    /// Returns the weighted sum of the numbers.
+    pub fn sum(&self, numbers: &[u8]) -> u32 {
+        numbers
+            .iter()
+            .rev()
+            .zip(self.weights.iter().cycle())
+            .map(|(&x, &y)| u32::from(x) * u32::from(y))
+            .sum()
+    }
+
+    /// Returns the check digit for a weighted sum.
+    pub fn digit_from_sum(&self, sum: u32) -> u8 {
+        let remainder = sum % 11;
+        let value = if self.complement {
+            11 - remainder
+        } else {
+            remainder
+        };
+
+        match value {
+            10 => self.ten,
+            11 => self.eleven,
+            _ => value as u8,
+        }
+    }
+
+    /// Computes the check digit of the numbers.
+    #[inline]
+    pub fn check_digit(&self, numbers: &[u8]) -> u8 {
+        self.digit_from_sum(self.sum(numbers))
+    }
+
+    /// Checks if the last number is the check digit of the preceding ones.
+    pub fn is_valid(&self, numbers: &[u8]) -> bool {
+        match numbers.split_last() {
+            Some((&check_digit, numbers)) => self.check_digit(numbers) == check_digit,
+            None => false,
+        }
+    }
+}
+
+/// A weighted modulo 10 check digit algorithm.
+///
+/// The weights are applied from the rightmost number to the leftmost one, starting over from the
+/// first weight when the numbers outnumber them. The check digit is `10` minus the remainder of
+/// the weighted sum, and the value `10` becomes `0`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mod10<'a> {
+    weights: &'a [u8],
+    sum_digits: bool,
+}
+
+/// The Luhn algorithm.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::checksum::LUHN;
+///
+/// assert_eq!(3, LUHN.check_digit(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1]));
+/// ```
+pub const LUHN: Mod10<'static> = Mod10::new(&[2, 1]).sum_digits();
+
+impl<'a> Mod10<'a> {
+    /// Creates a new algorithm with the weights, from the rightmost number to the leftmost one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` is empty.
+    pub const fn new(weights: &'a [u8]) -> Self {
+        assert!(!weights.is_empty(), "weights must not be empty");
+        Self {
+            weights,
+            sum_digits: false,
+        }
+    }
+
+    /// Sums the digits of each product, instead of the products themselves, as in the Luhn
+    /// algorithm.
+    #[inline]
+    pub const fn sum_digits(mut self) -> Self {
+        self.sum_digits = true;
+        self
+    }
+
+    /// Returns the weighted sum of the numbers.
+    pub fn sum(&self, numbers: &[u8]) -> u32 {
+        numbers
+            .iter()
+            .rev()
+            .zip(self.weights.iter().cycle())
+            .map(|(&x, &y)| {
+                let product = u32::from(x) * u32::from(y);
+                if self.sum_digits {
+                    product / 10 + product % 10
+                } else {
+                    product
+                }
+            })
+            .sum()
+    }
+
+    /// Computes the check digit of the numbers.
+    #[inline]
+    pub fn check_digit(&self, numbers: &[u8]) -> u8 {
+        ((10 - self.sum(numbers) % 10) % 10) as u8
+    }
+
+    /// Checks if the last number is the check digit of the preceding ones.
+    pub fn is_valid(&self, numbers: &[u8]) -> bool {
+        match numbers.split_last() {
+            Some((&check_digit, numbers)) => self.check_digit(numbers) == check_digit,
+            None => false,
+        }
+    }
+}
+
+/// Computes the two check digits of the numbers, using the ISO 7064 MOD 97-10 algorithm. The
+/// check digits are returned as a number in the range `2..=98`.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::checksum::mod97;
+///
+/// assert_eq!(44, mod97(&[7, 9, 4]));
+/// ```
+pub fn mod97(numbers: &[u8]) -> u8 {
+    (98 - mod97_remainder(numbers.iter().copied().chain([0, 0]))) as u8
+}
+
+/// Checks if the numbers, with the check digits at the end, are valid according to the
+/// ISO 7064 MOD 97-10 algorithm.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::checksum::mod97_is_valid;
+///
+/// assert!(mod97_is_valid(&[7, 9, 4, 4, 4]));
+/// ```
+pub fn mod97_is_valid(numbers: &[u8]) -> bool {
+    numbers.len() > 2 && mod97_remainder(numbers.iter().copied()) == 1
+}
+
+#[inline]
+fn mod97_remainder(numbers: impl IntoIterator<Item = u8>) -> u32 {
+    numbers
+        .into_iter()
+        .fold(0, |remainder, x| (remainder * 10 + u32::from(x)) % 97)
+}
+
+const VERHOEFF_D: [[u8; 10]; 10] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+    [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+    [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+    [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+    [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+    [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+    [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+    [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+    [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+const VERHOEFF_P: [[u8; 10]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+    [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+    [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+    [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+    [9, 4, 5, 3, 1, 2, 8, 7, 6, 0],
+    [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+    [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+    [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+];
+
+const VERHOEFF_INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+/// Computes the check digit of the numbers, using the Verhoeff algorithm.
+///
+/// # Panics
+///
+/// Panics if any number is greater than `9`.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::checksum::verhoeff;
+///
+/// assert_eq!(3, verhoeff(&[2, 3, 6]));
+/// ```
+pub fn verhoeff(numbers: &[u8]) -> u8 {
+    VERHOEFF_INV[usize::from(verhoeff_checksum(numbers, 1))]
+}
+
+/// Checks if the last number is the check digit of the preceding ones, according to the
+/// Verhoeff algorithm.
+///
+/// # Panics
+///
+/// Panics if any number is greater than `9`.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::checksum::verhoeff_is_valid;
+///
+/// assert!(verhoeff_is_valid(&[2, 3, 6, 3]));
+/// ```
+pub fn verhoeff_is_valid(numbers: &[u8]) -> bool {
+    !numbers.is_empty() && verhoeff_checksum(numbers, 0) == 0
+}
+
+#[inline]
+fn verhoeff_checksum(numbers: &[u8], offset: usize) -> u8 {
+    numbers
+        .iter()
+        .rev()
+        .enumerate()
+        .fold(0, |checksum, (i, &x)| {
+            let permuted = VERHOEFF_P[(i + offset) % 8][usize::from(x)];
+            VERHOEFF_D[usize::from(checksum)][usize::from(permuted)]
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mod11() {
+        let cpf = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+        let cnpj = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+        assert_eq!(0, cpf.check_digit(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        assert_eq!(9, cpf.check_digit(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]));
+        assert!(cnpj.is_valid(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1, 9]));
+        assert!(cnpj.is_valid(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1, 9, 5]));
+        assert!(!cnpj.is_valid(&[]));
+        assert_eq!(11, cpf.weight(0, 10));
+        assert_eq!(2, cnpj.weight(12, 13));
+        assert_eq!(9, cnpj.weight(5, 13));
+    }
+
+    #[test]
+    fn mod11_mapping() {
+        let weights = [2, 3, 4, 5, 6, 7, 8, 9];
+
+        // 1 * 9 = 9, remainder 9, complement 2
+        let numbers = [1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(2, Mod11::new(&weights).check_digit(&numbers));
+        assert_eq!(9, Mod11::new(&weights).remainder().check_digit(&numbers));
+
+        // 1 * 10 = 10, remainder 10, complement 1
+        let x = Mod11::new(&[10]).remainder().map_ten(X);
+        assert_eq!(X, x.check_digit(&[1]));
+
+        // 0, remainder 0, complement 11
+        let eleven = Mod11::new(&weights).map_eleven(1);
+        assert_eq!(1, eleven.check_digit(&[0]));
+
+        // 1 * 1 = 1, remainder 1, complement 10
+        let ten = Mod11::new(&[1]).map_ten(7);
+        assert_eq!(7, ten.check_digit(&[1]));
+    }
+
+    #[test]
+    fn luhn() {
+        assert_eq!(3, LUHN.check_digit(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1]));
+        assert!(LUHN.is_valid(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 3]));
+        assert!(!LUHN.is_valid(&[7, 9, 9, 2, 7, 3, 9, 8, 7, 1, 4]));
+
+        // IBGE code of São Paulo
+        assert!(LUHN.is_valid(&[3, 5, 5, 0, 3, 0, 8]));
+    }
+
+    #[test]
+    fn mod10() {
+        let mod10 = Mod10::new(&[2, 1]);
+        assert_eq!(8, mod10.check_digit(&[1, 2, 4]));
+        assert!(mod10.is_valid(&[1, 2, 4, 8]));
+    }
+
+    #[test]
+    fn mod97_10() {
+        assert_eq!(44, mod97(&[7, 9, 4]));
+        assert!(mod97_is_valid(&[7, 9, 4, 4, 4]));
+        assert!(!mod97_is_valid(&[7, 9, 4, 4, 5]));
+        assert!(!mod97_is_valid(&[1]));
+    }
+
+    #[test]
+    fn verhoeff_check_digit() {
+        assert_eq!(3, verhoeff(&[2, 3, 6]));
+        assert!(verhoeff_is_valid(&[2, 3, 6, 3]));
+        assert!(!verhoeff_is_valid(&[2, 3, 6, 4]));
+        assert!(!verhoeff_is_valid(&[]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn verhoeff_out_of_range() {
+        verhoeff(&[2, 3, 10]);
+    }
+}
@@ -0,0 +1,434 @@
+// cnh.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 9, 8, 7, ... 2, 1 from left to right
+const FIRST_CHECKSUM: Mod11 = Mod11::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9]).remainder();
+
+// 1, 2, 3, ... 8, 9 from left to right
+const SECOND_CHECKSUM: Mod11 = Mod11::new(&[9, 8, 7, 6, 5, 4, 3, 2, 1]).remainder();
+
+/// An error which can be returned when parsing an [`Cnh`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCnhError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseCnhError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseCnhError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid CNH number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCnhError {}
+
+/// A valid CNH (driver's license) registration number, also known as RENACH number. Parsing
+/// recognizes numbers without separators only.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cnh([u8; 11]);
+
+impl Cnh {
+    /// Parses a byte slice of numbers as a CNH, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Cnh;
+    ///
+    /// match Cnh::from_slice(&[0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1]) {
+    ///     Ok(cnh) => println!("{cnh} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digits:
+    ///
+    /// ```rust
+    /// use brids::Cnh;
+    ///
+    /// match Cnh::from_slice(&[0, 2, 6, 5, 0, 3, 0, 6, 4]) {
+    ///     Ok(cnh) => println!("{cnh} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseCnhError> {
+        let mut numbers = [0; 11];
+        match slice.len() {
+            0 => return Err(ParseCnhError::Empty),
+            len @ (9 | 11) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseCnhError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseCnhError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if slice.len() == 11 && numbers.iter().all(|&x| x == first_number) {
+            return Err(ParseCnhError::InvalidNumber);
+        }
+
+        let Some(check_digits) = calc_check_digits(&numbers[..9]) else {
+            return Err(ParseCnhError::InvalidNumber);
+        };
+        if slice.len() < 11 {
+            numbers[9..].copy_from_slice(&check_digits);
+        } else if check_digits != numbers[9..] {
+            return Err(ParseCnhError::InvalidNumber);
+        }
+
+        Ok(Cnh(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cnh;
+    ///
+    /// let cnh = "02650306461".parse::<Cnh>().expect("invalid CNH");
+    /// let digits = cnh.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 11] {
+        &self.0
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cnh;
+    ///
+    /// let cnh = Cnh::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Cnh {
+    const NAME: &'static str = "CNH";
+    const LEN: usize = 11;
+    const FORMATTED_LEN: usize = 11;
+
+    type Error = ParseCnhError;
+    type CheckDigits = [u8; 2];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..9]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[9..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 9) {
+            return Err(ParseCnhError::InvalidNumber);
+        }
+
+        calc_check_digits(base).ok_or(ParseCnhError::InvalidNumber)
+    }
+}
+
+impl AsRef<[u8]> for Cnh {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Cnh> for [u8; 11] {
+    #[inline]
+    fn from(cnh: Cnh) -> [u8; 11] {
+        cnh.0
+    }
+}
+
+impl TryFrom<&[u8]> for Cnh {
+    type Error = ParseCnhError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 11]> for Cnh {
+    type Error = ParseCnhError;
+
+    #[inline]
+    fn try_from(value: &[u8; 11]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Cnh {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cnh(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Cnh {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for number in &self.0 {
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cnh {
+    type Err = ParseCnhError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 11];
+
+        if s.is_empty() {
+            return Err(ParseCnhError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match ch {
+                '0'..='9' => {
+                    if i < 11 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseCnhError::InvalidNumber);
+                    }
+                }
+                _ => return Err(ParseCnhError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 11 {
+            return Err(ParseCnhError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Cnh> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cnh {
+        let mut numbers = [0; 11];
+        for number in &mut numbers[..9] {
+            *number = rng.random_range(0..=9);
+        }
+
+        match calc_check_digits(&numbers[..9]) {
+            Some(check_digits) => {
+                numbers[9..].copy_from_slice(&check_digits);
+                Cnh(numbers)
+            }
+            // No valid check digits
+            None => self.sample(rng),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cnh {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cnh {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+/// Returns `None` if no number with the base is valid.
+fn calc_check_digits(base: &[u8]) -> Option<[u8; 2]> {
+    let first_sum = FIRST_CHECKSUM.sum(base);
+    let second_sum = SECOND_CHECKSUM.sum(base);
+    let mut second_check_digit = SECOND_CHECKSUM.digit_from_sum(second_sum);
+
+    // When the first remainder is `10`, the second check digit is discounted by 2, and a negative
+    // result is never valid
+    if first_sum % 11 == 10 && second_sum % 11 != 10 {
+        second_check_digit = second_check_digit.checked_sub(2)?;
+    }
+
+    Some([FIRST_CHECKSUM.digit_from_sum(first_sum), second_check_digit])
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Cnh([0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1]);
+        let b: [u8; 11] = [0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1];
+        let c: [u8; 9] = [0, 2, 6, 5, 0, 3, 0, 6, 4];
+
+        assert_eq!(a, Cnh::from_slice(&b).unwrap());
+        assert_eq!(a, Cnh::from_slice(&c).unwrap());
+    }
+
+    #[test]
+    fn check_digits() {
+        assert_eq!(
+            Some([6, 1]),
+            calc_check_digits(&[0, 2, 6, 5, 0, 3, 0, 6, 4])
+        );
+
+        // Discount rule, the second remainder is 4
+        assert_eq!(
+            Some([0, 2]),
+            calc_check_digits(&[8, 7, 0, 5, 9, 6, 4, 0, 2])
+        );
+
+        // Discount rule, the second remainder is 0
+        let base = [9, 2, 9, 4, 7, 8, 2, 2, 2];
+        assert_eq!(None, calc_check_digits(&base));
+        assert_eq!(Cnh::from_slice(&base), Err(ParseCnhError::InvalidNumber));
+        assert_eq!(
+            Cnh::from_slice(&[9, 2, 9, 4, 7, 8, 2, 2, 2, 0, 9]),
+            Err(ParseCnhError::InvalidNumber)
+        );
+        assert_eq!(
+            Cnh::compute_check_digits(&base),
+            Err(ParseCnhError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn as_bytes() {
+        let a: [u8; 11] = [0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1];
+        let b = Cnh([0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1]);
+
+        assert_eq!(&a, b.as_bytes());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Cnh::generate();
+        let b = a.to_string().parse::<Cnh>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn document() {
+        let a = Cnh([0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1]);
+
+        assert_eq!(&[0, 2, 6, 5, 0, 3, 0, 6, 4], a.base());
+        assert_eq!(&[6, 1], a.check_digits());
+        assert_eq!(
+            Ok([6, 1]),
+            Cnh::compute_check_digits(&[0, 2, 6, 5, 0, 3, 0, 6, 4])
+        );
+        assert_eq!(Cnh::FORMATTED_LEN, format!("{a}").len());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Cnh("02650306461")"#;
+        let b = Cnh([0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "02650306461".parse::<Cnh>().unwrap();
+        let b = "87059640202".parse::<Cnh>().unwrap();
+
+        assert_eq!(a, Cnh([0, 2, 6, 5, 0, 3, 0, 6, 4, 6, 1]));
+        assert_eq!(b, Cnh([8, 7, 0, 5, 9, 6, 4, 0, 2, 0, 2]));
+        assert_eq!("".parse::<Cnh>(), Err(ParseCnhError::Empty));
+        assert_eq!(
+            "026.503.064-61".parse::<Cnh>(),
+            Err(ParseCnhError::InvalidCharacter('.', 3))
+        );
+        assert_eq!(
+            "02650306462".parse::<Cnh>(),
+            Err(ParseCnhError::InvalidNumber)
+        );
+        assert_eq!(
+            "026503064610".parse::<Cnh>(),
+            Err(ParseCnhError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let cnh_str = "02650306461";
+        let cnh = Cnh::from_str(cnh_str).unwrap();
+        serde_test::assert_tokens(&cnh, &[serde_test::Token::Str(cnh_str)]);
+    }
+}
@@ -0,0 +1,387 @@
+// cno.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod10;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 7, 4, 1, 8, 5, 2, 1, 6, 3, 7, 4, applied from left to right
+const CHECKSUM: Mod10 = Mod10::new(&[4, 7, 3, 6, 1, 2, 5, 8, 1, 4, 7]);
+
+/// An error which can be returned when parsing a [`Cno`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCnoError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseCnoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseCnoError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid CNO number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCnoError {}
+
+/// A valid CNO (_Cadastro Nacional de Obras_) number, which identifies a construction site. It
+/// replaced the CEI (_Cadastro Específico do INSS_), which shares the same format, so CEI numbers
+/// are also accepted. Parsing recognizes numbers with or without separators (dot and slash).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cno([u8; 12]);
+
+impl Cno {
+    /// Parses a byte slice of numbers as a CNO, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Cno;
+    ///
+    /// match Cno::from_slice(&[5, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 7]) {
+    ///     Ok(cno) => println!("{cno} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::Cno;
+    ///
+    /// match Cno::from_slice(&[5, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0]) {
+    ///     Ok(cno) => println!("{cno} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseCnoError> {
+        let mut numbers = [0; 12];
+        match slice.len() {
+            0 => return Err(ParseCnoError::Empty),
+            len @ (11 | 12) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseCnoError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseCnoError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers[..11].iter().all(|&x| x == first_number) {
+            return Err(ParseCnoError::InvalidNumber);
+        }
+
+        let check_digit = calc_check_digit(&numbers[..11]);
+        if slice.len() == 11 {
+            numbers[11] = check_digit;
+        } else if check_digit != numbers[11] {
+            return Err(ParseCnoError::InvalidNumber);
+        }
+
+        Ok(Cno(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cno;
+    ///
+    /// let cno = "51.234.56789/07".parse::<Cno>().expect("invalid CNO");
+    /// let digits = cno.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 12] {
+        &self.0
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cno;
+    ///
+    /// let cno = Cno::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Cno {
+    const NAME: &'static str = "CNO";
+    const LEN: usize = 12;
+    const FORMATTED_LEN: usize = 15;
+
+    type Error = ParseCnoError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..11]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[11..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 11) {
+            return Err(ParseCnoError::InvalidNumber);
+        }
+
+        Ok([calc_check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for Cno {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Cno> for [u8; 12] {
+    #[inline]
+    fn from(cno: Cno) -> [u8; 12] {
+        cno.0
+    }
+}
+
+impl TryFrom<&[u8]> for Cno {
+    type Error = ParseCnoError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 12]> for Cno {
+    type Error = ParseCnoError;
+
+    #[inline]
+    fn try_from(value: &[u8; 12]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Cno {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cno(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Cno {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                2 | 5 => f.write_char('.')?,
+                10 => f.write_char('/')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cno {
+    type Err = ParseCnoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 12];
+
+        if s.is_empty() {
+            return Err(ParseCnoError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 12 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseCnoError::InvalidNumber);
+                    }
+                }
+                ('.', 2 | 6) | ('/', 12) => continue,
+                _ => return Err(ParseCnoError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 12 {
+            return Err(ParseCnoError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+fn calc_check_digit(base: &[u8]) -> u8 {
+    // Adds the units and the tens of the weighted sum
+    let sum = CHECKSUM.sum(base);
+    let sum = sum % 10 + sum / 10 % 10;
+    ((10 - sum % 10) % 10) as u8
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Cno> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cno {
+        let mut numbers = [0; 12];
+        for number in &mut numbers[..11] {
+            *number = rng.random_range(0..=9);
+        }
+
+        // Repeated numbers
+        let first_number = numbers[0];
+        if numbers[..11].iter().all(|&x| x == first_number) {
+            return self.sample(rng);
+        }
+
+        numbers[11] = calc_check_digit(&numbers[..11]); // check digit
+
+        Cno(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cno {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cno {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Cno([5, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 7]);
+
+        assert_eq!(Ok(a), Cno::from_slice(&a.0));
+        assert_eq!(Ok(a), Cno::from_slice(&a.0[..11]));
+        assert!(Cno::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1, 0]).is_ok());
+        assert_eq!(
+            Cno::from_slice(&[5, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 8]),
+            Err(ParseCnoError::InvalidNumber)
+        );
+        assert_eq!(
+            Cno::from_slice(&[1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 8]),
+            Err(ParseCnoError::InvalidNumber)
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Cno::generate();
+        let b = a.to_string().parse::<Cno>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Cno("51.234.56789/07")"#;
+        let b = Cno([5, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 7]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "51.234.56789/07".parse::<Cno>().unwrap();
+        let b = "512345678907".parse::<Cno>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!("".parse::<Cno>(), Err(ParseCnoError::Empty));
+        assert_eq!(
+            "51.234.56789-07".parse::<Cno>(),
+            Err(ParseCnoError::InvalidCharacter('-', 12))
+        );
+        assert_eq!(
+            "51.234.56789/08".parse::<Cno>(),
+            Err(ParseCnoError::InvalidNumber)
+        );
+        assert_eq!(
+            "51.234.56789/0".parse::<Cno>(),
+            Err(ParseCnoError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let cno_str = "51.234.56789/07";
+        let cno = Cno::from_str(cno_str).unwrap();
+        serde_test::assert_tokens(&cno, &[serde_test::Token::Str(cno_str)]);
+    }
+}
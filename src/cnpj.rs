@@ -14,6 +14,11 @@ use core::convert::TryFrom;
 use core::fmt::{self, Write};
 use core::str::FromStr;
 
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+use crate::explain::Explanation;
+use crate::suspicion::{self, Suspicion};
+
 #[cfg(feature = "rand")]
 use rand::{
     Rng,
@@ -23,6 +28,19 @@ use rand::{
 #[cfg(feature = "serde")]
 use serde::*;
 
+// Applied from right to left; the second check digit includes the first one
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+// Used as examples in documentation, including this crate's
+const KNOWN_EXAMPLES: [Cnpj; 1] = [Cnpj([1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1, 9, 5])];
+
+// Widely used in tests and sandbox environments
+const KNOWN_TEST_NUMBERS: [Cnpj; 3] = [
+    Cnpj([1, 1, 2, 2, 2, 3, 3, 3, 0, 0, 0, 1, 8, 1]),
+    Cnpj([1, 1, 4, 4, 4, 7, 7, 7, 0, 0, 0, 1, 6, 1]),
+    Cnpj([9, 9, 9, 9, 9, 9, 9, 9, 0, 0, 0, 1, 9, 1]),
+];
+
 /// An error which can be returned when parsing an [`Cnpj`] number.
 #[derive(Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -30,6 +48,14 @@ pub enum ParseCnpjError {
     Empty,
     InvalidCharacter(char, usize),
     InvalidNumber,
+    /// The branch is `0000`, which is never issued.
+    ZeroBranch,
+    /// The root is `00000000`.
+    ZeroRoot,
+    /// The branch is greater than the maximum allowed by the [`CnpjRules`].
+    BranchOutOfRange(u16),
+    /// The branch is missing, and the [`CnpjRules`] forbid guessing it.
+    MissingBranch,
 }
 
 impl fmt::Display for ParseCnpjError {
@@ -41,6 +67,10 @@ impl fmt::Display for ParseCnpjError {
                 write!(f, "invalid character `{ch}` at offset {offset}")
             }
             InvalidNumber => write!(f, "invalid CNPJ number"),
+            ZeroBranch => write!(f, "branch `0000` is never issued"),
+            ZeroRoot => write!(f, "root `00000000` is not allowed"),
+            BranchOutOfRange(branch) => write!(f, "branch {branch} is out of range"),
+            MissingBranch => write!(f, "missing branch"),
         }
     }
 }
@@ -114,12 +144,12 @@ impl Cnpj {
         }
 
         for i in 0..=1 {
-            let remainder = calc_remainder(numbers, i);
+            let expected = CHECKSUM.check_digit(&numbers[..12 + i]);
             let check_digit = numbers[12 + i];
 
             if slice.len() < 14 {
-                numbers[12 + i] = remainder; // check digit
-            } else if remainder != check_digit {
+                numbers[12 + i] = expected; // check digit
+            } else if expected != check_digit {
                 return Err(ParseCnpjError::InvalidNumber);
             }
         }
@@ -142,6 +172,21 @@ impl Cnpj {
         &self.0
     }
 
+    /// Explains the calculation of the check digits, step by step, even if they are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cnpj;
+    ///
+    /// let explanation = Cnpj::explain("12.345.678/0001-96").expect("malformed CNPJ");
+    /// assert!(!explanation.is_valid());
+    /// println!("{explanation}");
+    /// ```
+    pub fn explain(s: &str) -> Result<Explanation, ParseCnpjError> {
+        parse_numbers(s).map(|numbers| Explanation::new(CHECKSUM, &numbers))
+    }
+
     /// Returns the entity branch/subsidiary.
     ///
     /// # Examples
@@ -162,6 +207,47 @@ impl Cnpj {
             .sum()
     }
 
+    /// Analyzes the number, returning the reasons to suspect that it is a placeholder or a fraud.
+    /// The patterns are checked on the root.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cnpj, Suspicion};
+    ///
+    /// let cnpj = "12.345.678/0001-95".parse::<Cnpj>().expect("invalid CNPJ");
+    /// assert!(cnpj.suspicion().contains(Suspicion::KNOWN_EXAMPLE));
+    /// ```
+    #[inline]
+    pub fn suspicion(&self) -> Suspicion {
+        self.suspicion_with(&[])
+    }
+
+    /// Analyzes the number like [`Cnpj::suspicion`], also checking if it is in the `known` list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cnpj, Suspicion};
+    ///
+    /// let blocked = ["11.222.333/0001-81".parse::<Cnpj>().expect("invalid CNPJ")];
+    /// let cnpj = "11.222.333/0001-81".parse::<Cnpj>().expect("invalid CNPJ");
+    /// assert!(cnpj.suspicion_with(&blocked).contains(Suspicion::LISTED));
+    /// ```
+    pub fn suspicion_with(&self, known: &[Self]) -> Suspicion {
+        let mut suspicion = suspicion::analyze(&self.0[..8]);
+        if KNOWN_EXAMPLES.contains(self) {
+            suspicion |= Suspicion::KNOWN_EXAMPLE;
+        }
+        if KNOWN_TEST_NUMBERS.contains(self) {
+            suspicion |= Suspicion::KNOWN_TEST_NUMBER;
+        }
+        if known.contains(self) {
+            suspicion |= Suspicion::LISTED;
+        }
+        suspicion
+    }
+
     /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
     /// To use a different generator, instantiate the generator directly. The random CNPJ will be
     /// the company headquarters.
@@ -180,6 +266,165 @@ impl Cnpj {
     }
 }
 
+/// Semantic validation rules for [`Cnpj`] numbers, beyond the check digits.
+///
+/// By default, only the [`nonzero_branch`](CnpjRules::nonzero_branch) rule is enabled. The
+/// [`nonzero_root`](CnpjRules::nonzero_root) rule is opt-in, because the root of Banco do Brasil
+/// S/A is `00000000`.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::{CnpjRules, ParseCnpjError};
+///
+/// let rules = CnpjRules::new().max_branch(Some(500));
+///
+/// assert!(rules.parse("12.345.678/0001-95").is_ok());
+/// assert_eq!(rules.parse("12.345.678/0000-04"), Err(ParseCnpjError::ZeroBranch));
+/// assert_eq!(
+///     rules.parse("12.345.678/0999-73"),
+///     Err(ParseCnpjError::BranchOutOfRange(999))
+/// );
+/// assert_eq!(
+///     rules.from_slice(&[1, 2, 3, 4, 5, 6, 7, 8]),
+///     Err(ParseCnpjError::MissingBranch)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CnpjRules {
+    nonzero_branch: bool,
+    nonzero_root: bool,
+    max_branch: Option<u16>,
+    guess_branch: bool,
+}
+
+impl CnpjRules {
+    /// Creates the default rules.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            nonzero_branch: true,
+            nonzero_root: false,
+            max_branch: None,
+            guess_branch: false,
+        }
+    }
+
+    /// Requires the branch to be greater than or equal to `0001`. Enabled by default.
+    #[inline]
+    pub const fn nonzero_branch(mut self, enabled: bool) -> Self {
+        self.nonzero_branch = enabled;
+        self
+    }
+
+    /// Requires the root to be different from `00000000`. Disabled by default, since it rejects
+    /// Banco do Brasil S/A (`00.000.000/0001-91`).
+    #[inline]
+    pub const fn nonzero_root(mut self, enabled: bool) -> Self {
+        self.nonzero_root = enabled;
+        self
+    }
+
+    /// Sets the maximum plausible branch. Disabled by default.
+    #[inline]
+    pub const fn max_branch(mut self, max: Option<u16>) -> Self {
+        self.max_branch = max;
+        self
+    }
+
+    /// Allows [`CnpjRules::from_slice`] to guess the branch `0001` when only the root is given.
+    /// Disabled by default.
+    #[inline]
+    pub const fn guess_branch(mut self, enabled: bool) -> Self {
+        self.guess_branch = enabled;
+        self
+    }
+
+    /// Checks if a valid number follows the rules.
+    pub fn validate(&self, cnpj: &Cnpj) -> Result<(), ParseCnpjError> {
+        if self.nonzero_root && cnpj.0[..8].iter().all(|&x| x == 0) {
+            return Err(ParseCnpjError::ZeroRoot);
+        }
+
+        let branch = cnpj.branch();
+        if self.nonzero_branch && branch == 0 {
+            return Err(ParseCnpjError::ZeroBranch);
+        }
+        match self.max_branch {
+            Some(max) if branch > max => Err(ParseCnpjError::BranchOutOfRange(branch)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Parses a string as a CNPJ, checking the rules.
+    pub fn parse(&self, s: &str) -> Result<Cnpj, ParseCnpjError> {
+        let cnpj = s.parse()?;
+        self.validate(&cnpj)?;
+        Ok(cnpj)
+    }
+
+    /// Parses a byte slice of numbers as a CNPJ, like [`Cnpj::from_slice`], checking the rules.
+    pub fn from_slice(&self, slice: &[u8]) -> Result<Cnpj, ParseCnpjError> {
+        if slice.len() == 8 && !self.guess_branch {
+            return Err(ParseCnpjError::MissingBranch);
+        }
+
+        let cnpj = Cnpj::from_slice(slice)?;
+        self.validate(&cnpj)?;
+        Ok(cnpj)
+    }
+}
+
+impl Default for CnpjRules {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Document for Cnpj {
+    const NAME: &'static str = "CNPJ";
+    const LEN: usize = 14;
+    const FORMATTED_LEN: usize = 18;
+
+    type Error = ParseCnpjError;
+    type CheckDigits = [u8; 2];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..12]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[12..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 12) {
+            return Err(ParseCnpjError::InvalidNumber);
+        }
+
+        let mut numbers = [0; 14];
+        numbers[..12].copy_from_slice(base);
+        for i in 0..=1 {
+            numbers[12 + i] = CHECKSUM.check_digit(&numbers[..12 + i]);
+        }
+
+        Ok([numbers[12], numbers[12 + 1]])
+    }
+}
+
 impl AsRef<[u8]> for Cnpj {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -237,39 +482,7 @@ impl FromStr for Cnpj {
     type Err = ParseCnpjError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut numbers = [0; 14];
-
-        if s.is_empty() {
-            return Err(ParseCnpjError::Empty);
-        }
-
-        // Checks for invalid symbols and converts numbers to integers
-        let mut i = 0;
-        let mut has_dot = false;
-        for (offset, ch) in s.chars().enumerate() {
-            match (ch, offset) {
-                ('0'..='9', _) => {
-                    if i < 14 {
-                        // SAFETY: Digit already matched
-                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
-                        i += 1;
-                    } else {
-                        return Err(ParseCnpjError::InvalidNumber);
-                    }
-                }
-                ('.', 2 | 6) => has_dot = true,
-                ('/', 10) if has_dot => continue,
-                ('/', 8) if !has_dot => continue,
-                ('-', 15) if has_dot => continue,
-                ('-', 13) if !has_dot => continue,
-                _ => return Err(ParseCnpjError::InvalidCharacter(ch, offset)),
-            }
-        }
-
-        // Checks the length
-        if i != 14 {
-            return Err(ParseCnpjError::InvalidNumber);
-        }
+        let numbers = parse_numbers(s)?;
 
         // Checks for repeated numbers
         let first_number = numbers[0];
@@ -278,10 +491,10 @@ impl FromStr for Cnpj {
         }
 
         for i in 0..=1 {
-            let remainder = calc_remainder(numbers, i);
+            let expected = CHECKSUM.check_digit(&numbers[..12 + i]);
             let check_digit = numbers[12 + i];
 
-            if remainder != check_digit {
+            if expected != check_digit {
                 return Err(ParseCnpjError::InvalidNumber);
             }
         }
@@ -300,7 +513,7 @@ impl Distribution<Cnpj> for StandardUniform {
         numbers[11] = 1; // `0001` (company headquarters)
 
         for i in 0..=1 {
-            numbers[12 + i] = calc_remainder(numbers, i); // check digit
+            numbers[12 + i] = CHECKSUM.check_digit(&numbers[..12 + i]); // check digit
         }
 
         Cnpj(numbers)
@@ -309,56 +522,57 @@ impl Distribution<Cnpj> for StandardUniform {
 
 #[cfg(feature = "serde")]
 impl Serialize for Cnpj {
+    #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        #[cfg(not(feature = "std"))]
-        use crate::alloc::string::ToString;
-
-        serializer.serialize_str(&self.to_string())
+        document::serialize(self, serializer)
     }
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Cnpj {
+    #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        struct CnpjStringVisitor;
-
-        impl<'vi> de::Visitor<'vi> for CnpjStringVisitor {
-            type Value = Cnpj;
+        document::deserialize(deserializer)
+    }
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "a CNPJ string")
-            }
+/// Converts the numbers of a string to integers, checking the separators and the length.
+fn parse_numbers(s: &str) -> Result<[u8; 14], ParseCnpjError> {
+    let mut numbers = [0; 14];
 
-            fn visit_str<E: de::Error>(self, value: &str) -> Result<Cnpj, E> {
-                value.parse().map_err(E::custom)
-            }
+    if s.is_empty() {
+        return Err(ParseCnpjError::Empty);
+    }
 
-            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Cnpj, E> {
-                Cnpj::try_from(value).map_err(E::custom)
+    // Checks for invalid symbols and converts numbers to integers
+    let mut i = 0;
+    let mut has_dot = false;
+    for (offset, ch) in s.chars().enumerate() {
+        match (ch, offset) {
+            ('0'..='9', _) => {
+                if i < 14 {
+                    // SAFETY: Digit already matched
+                    numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                    i += 1;
+                } else {
+                    return Err(ParseCnpjError::InvalidNumber);
+                }
             }
+            ('.', 2 | 6) => has_dot = true,
+            ('/', 10) if has_dot => continue,
+            ('/', 8) if !has_dot => continue,
+            ('-', 15) if has_dot => continue,
+            ('-', 13) if !has_dot => continue,
+            _ => return Err(ParseCnpjError::InvalidCharacter(ch, offset)),
         }
-
-        deserializer.deserialize_str(CnpjStringVisitor)
     }
-}
 
-#[inline]
-fn calc_remainder(numbers: impl IntoIterator<Item = u8>, i: usize) -> u8 {
-    let remainder = numbers
-        .into_iter()
-        // Includes the first check digit in the second iteration
-        .take(12 + i)
-        // 5, 4, 3, 2, 9, 8, 7, ... 3, 2; and after: 6, 5, 4, 3, 2, 9, 8, 7, ... 3, 2
-        .zip((2..=9).chain(2..=5 + i).rev())
-        .map(|(x, y)| u32::from(x) * y as u32)
-        .sum::<u32>()
-        * 10
-        % 11;
-
-    match remainder {
-        10 | 11 => 0,
-        _ => remainder as u8,
+    // Checks the length
+    if i != 14 {
+        return Err(ParseCnpjError::InvalidNumber);
     }
+
+    Ok(numbers)
 }
 
 #[cfg(test)]
@@ -367,6 +581,7 @@ mod tests {
     use alloc::format;
 
     use super::*;
+    use crate::DisplayStyle;
 
     #[test]
     fn from_slice() {
@@ -406,6 +621,27 @@ mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn document() {
+        let a = Cnpj([1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1, 9, 5]);
+
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1], a.base());
+        assert_eq!(&[9, 5], a.check_digits());
+        assert_eq!(
+            Ok([9, 5]),
+            Cnpj::compute_check_digits(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1])
+        );
+        assert_eq!(
+            Err(ParseCnpjError::InvalidNumber),
+            Cnpj::compute_check_digits(&[1, 2, 3])
+        );
+        assert_eq!(Cnpj::FORMATTED_LEN, format!("{a}").len());
+        assert_eq!(
+            "12345678000195",
+            format!("{}", a.display_style(DisplayStyle::Digits))
+        );
+    }
+
     #[test]
     fn as_ref() {
         fn test_trait<T: AsRef<[u8]>>(b: T) {
@@ -481,6 +717,81 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explain() {
+        let a = Cnpj::explain("12.345.678/0001-96").unwrap();
+        let [first, second] = a.steps();
+
+        assert_eq!(12, first.terms().len());
+        assert_eq!(5, first.terms()[0].weight());
+        assert_eq!(9, first.terms()[4].weight());
+        assert!(first.is_valid());
+        assert_eq!(13, second.terms().len());
+        assert_eq!(5, second.expected());
+        assert_eq!(6, second.found());
+        assert!(!a.is_valid());
+        assert!(Cnpj::explain("12.345.678/0001-95").unwrap().is_valid());
+
+        // Repeated numbers
+        let b = Cnpj::explain("00.000.000/0000-00").unwrap();
+        assert!(b.steps().iter().all(|step| step.is_valid()));
+        assert!(b.is_repeated());
+        assert!(!b.is_valid());
+        assert_eq!(Cnpj::explain(""), Err(ParseCnpjError::Empty));
+    }
+
+    #[test]
+    fn suspicion() {
+        for known in KNOWN_EXAMPLES.iter().chain(&KNOWN_TEST_NUMBERS) {
+            assert_eq!(Ok(*known), Cnpj::try_from(known.as_bytes()));
+        }
+
+        let a = Cnpj([1, 1, 2, 2, 2, 3, 3, 3, 0, 0, 0, 1, 8, 1]);
+        assert_eq!(Suspicion::KNOWN_TEST_NUMBER, a.suspicion());
+        assert_eq!(
+            Suspicion::KNOWN_TEST_NUMBER | Suspicion::LISTED,
+            a.suspicion_with(&[a])
+        );
+        assert!(
+            Cnpj::from_slice(&[4, 7, 9, 6, 0, 9, 5, 2])
+                .unwrap()
+                .suspicion()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn rules() {
+        let rules = CnpjRules::new();
+        let bb = Cnpj([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 9, 1]);
+        let zero_branch = Cnpj::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0]).unwrap();
+
+        assert_eq!(Ok(()), rules.validate(&bb));
+        assert_eq!(
+            Err(ParseCnpjError::ZeroRoot),
+            rules.nonzero_root(true).validate(&bb)
+        );
+        assert_eq!(
+            Err(ParseCnpjError::ZeroBranch),
+            rules.validate(&zero_branch)
+        );
+        assert_eq!(Ok(()), rules.nonzero_branch(false).validate(&zero_branch));
+        assert_eq!(
+            Err(ParseCnpjError::BranchOutOfRange(27)),
+            rules.max_branch(Some(26)).parse("12.345.678/0027-24")
+        );
+        assert_eq!(
+            Err(ParseCnpjError::MissingBranch),
+            rules.from_slice(&[1, 2, 3, 4, 5, 6, 7, 8])
+        );
+        assert_eq!(
+            Ok(Cnpj([1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 1, 9, 5])),
+            rules
+                .guess_branch(true)
+                .from_slice(&[1, 2, 3, 4, 5, 6, 7, 8])
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize() {
@@ -0,0 +1,555 @@
+// cns.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 15, 14, ..., 1, applied from left to right
+const CHECKSUM: Mod11 = Mod11::new(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+// 15, 14, ..., 5, applied to the PIS of definitive numbers
+const PIS_CHECKSUM: Mod11 = Mod11::new(&[5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]);
+
+/// An error which can be returned when parsing a [`Cns`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseCnsError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseCnsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseCnsError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid CNS number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCnsError {}
+
+/// The kind of a [`Cns`] number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CnsKind {
+    /// A definitive number, starting with 1 or 2 and derived from the PIS.
+    Definitive,
+    /// A provisional number, starting with 7, 8 or 9.
+    Provisional,
+}
+
+/// The check digits of a [`Cns`] number, returned by [`Document::compute_check_digits`]. These
+/// are the last 4 numbers of definitive numbers, or the last number of provisional ones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CnsCheckDigits {
+    numbers: [u8; 4],
+    len: usize,
+}
+
+impl AsRef<[u8]> for CnsCheckDigits {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.numbers[..self.len]
+    }
+}
+
+/// A valid CNS (_Cartão Nacional de Saúde_) number. Parsing recognizes numbers with or without
+/// separators (space).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cns([u8; 15]);
+
+impl Cns {
+    /// Parses a byte slice of numbers as a CNS, guessing the missing parts. Slices of 11 numbers
+    /// are taken as the PIS of a definitive number, and slices of 14 numbers as a provisional
+    /// number without the check digit.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Cns;
+    ///
+    /// match Cns::from_slice(&[8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1]) {
+    ///     Ok(cns) => println!("{cns} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digits from the PIS:
+    ///
+    /// ```rust
+    /// use brids::Cns;
+    ///
+    /// match Cns::from_slice(&[1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4]) {
+    ///     Ok(cns) => println!("{cns} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseCnsError> {
+        let mut numbers = [0; 15];
+        match slice.len() {
+            0 => return Err(ParseCnsError::Empty),
+            len @ (11 | 14 | 15) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseCnsError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseCnsError::InvalidNumber);
+        }
+
+        match (kind_of(numbers[0]), slice.len()) {
+            (Some(CnsKind::Definitive), 11) => {
+                let check_digits = calc_check_digits(&numbers[..11]);
+                numbers[11..].copy_from_slice(&check_digits);
+            }
+            (Some(CnsKind::Provisional), 14) => match calc_check_digit(&numbers[..14]) {
+                Some(digit) => numbers[14] = digit,
+                None => return Err(ParseCnsError::InvalidNumber),
+            },
+            (Some(_), 15) if is_valid(&numbers) => (),
+            _ => return Err(ParseCnsError::InvalidNumber),
+        }
+
+        Ok(Cns(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cns;
+    ///
+    /// let cns = "898 0011 6066 0001".parse::<Cns>().expect("invalid CNS");
+    /// let digits = cns.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 15] {
+        &self.0
+    }
+
+    /// Returns the kind of the number.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cns, CnsKind};
+    ///
+    /// let cns = "170 3325 9504 0000".parse::<Cns>().expect("invalid CNS");
+    /// assert_eq!(CnsKind::Definitive, cns.kind());
+    ///
+    /// let cns = "898 0011 6066 0001".parse::<Cns>().expect("invalid CNS");
+    /// assert_eq!(CnsKind::Provisional, cns.kind());
+    /// ```
+    #[inline]
+    pub fn kind(&self) -> CnsKind {
+        match self.0[0] {
+            1 | 2 => CnsKind::Definitive,
+            _ => CnsKind::Provisional,
+        }
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cns;
+    ///
+    /// let cns = Cns::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Cns {
+    const NAME: &'static str = "CNS";
+    const LEN: usize = 15;
+    const FORMATTED_LEN: usize = 18;
+
+    type Error = ParseCnsError;
+    type CheckDigits = CnsCheckDigits;
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        match self.kind() {
+            CnsKind::Definitive => &self.0[..11],
+            CnsKind::Provisional => &self.0[..14],
+        }
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        match self.kind() {
+            CnsKind::Definitive => &self.0[11..],
+            CnsKind::Provisional => &self.0[14..],
+        }
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        let kind = base.first().and_then(|&x| kind_of(x));
+        match kind {
+            Some(CnsKind::Definitive) if document::check_base(base, 11) => Ok(CnsCheckDigits {
+                numbers: calc_check_digits(base),
+                len: 4,
+            }),
+            Some(CnsKind::Provisional) if document::check_base(base, 14) => {
+                match calc_check_digit(base) {
+                    Some(digit) => Ok(CnsCheckDigits {
+                        numbers: [digit, 0, 0, 0],
+                        len: 1,
+                    }),
+                    None => Err(ParseCnsError::InvalidNumber),
+                }
+            }
+            _ => Err(ParseCnsError::InvalidNumber),
+        }
+    }
+}
+
+impl AsRef<[u8]> for Cns {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Cns> for [u8; 15] {
+    #[inline]
+    fn from(cns: Cns) -> [u8; 15] {
+        cns.0
+    }
+}
+
+impl TryFrom<&[u8]> for Cns {
+    type Error = ParseCnsError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 15]> for Cns {
+    type Error = ParseCnsError;
+
+    #[inline]
+    fn try_from(value: &[u8; 15]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Cns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Cns(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Cns {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            if let 3 | 7 | 11 = i {
+                f.write_char(' ')?;
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Cns {
+    type Err = ParseCnsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 15];
+
+        if s.is_empty() {
+            return Err(ParseCnsError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 15 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseCnsError::InvalidNumber);
+                    }
+                }
+                (' ', 3 | 8 | 13) => continue,
+                _ => return Err(ParseCnsError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 15 || !is_valid(&numbers) {
+            return Err(ParseCnsError::InvalidNumber);
+        }
+
+        Ok(Cns(numbers))
+    }
+}
+
+fn kind_of(first_number: u8) -> Option<CnsKind> {
+    match first_number {
+        1 | 2 => Some(CnsKind::Definitive),
+        7..=9 => Some(CnsKind::Provisional),
+        _ => None,
+    }
+}
+
+/// Computes the last 4 numbers of a definitive number from the PIS.
+fn calc_check_digits(pis: &[u8]) -> [u8; 4] {
+    let sum = PIS_CHECKSUM.sum(pis);
+    match 11 - sum % 11 {
+        11 => [0; 4],
+        // Adds 2 to the sum, flagged by `001`
+        10 => [0, 0, 1, (11 - (sum + 2) % 11) as u8],
+        digit => [0, 0, 0, digit as u8],
+    }
+}
+
+/// Computes the last number of a provisional number, if there is one.
+fn calc_check_digit(base: &[u8]) -> Option<u8> {
+    let mut numbers = [0; 15];
+    numbers[..14].copy_from_slice(base);
+
+    // The last weight is 1, so the check digit is added to the sum as is
+    match (11 - CHECKSUM.sum(&numbers) % 11) % 11 {
+        10 => None,
+        digit => Some(digit as u8),
+    }
+}
+
+fn is_valid(numbers: &[u8; 15]) -> bool {
+    match kind_of(numbers[0]) {
+        Some(CnsKind::Definitive) => calc_check_digits(&numbers[..11]) == numbers[11..],
+        Some(CnsKind::Provisional) => CHECKSUM.sum(numbers) % 11 == 0,
+        None => false,
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Cns> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cns {
+        let mut numbers = [0; 15];
+        if rng.random() {
+            numbers[0] = rng.random_range(1..=2);
+            for number in &mut numbers[1..11] {
+                *number = rng.random_range(0..=9);
+            }
+            let check_digits = calc_check_digits(&numbers[..11]);
+            numbers[11..].copy_from_slice(&check_digits);
+        } else {
+            numbers[0] = rng.random_range(7..=9);
+            loop {
+                for number in &mut numbers[1..14] {
+                    *number = rng.random_range(0..=9);
+                }
+                if let Some(digit) = calc_check_digit(&numbers[..14]) {
+                    numbers[14] = digit;
+                    break;
+                }
+            }
+        }
+
+        Cns(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Cns {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Cns {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Cns([1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4, 0, 0, 0, 0]);
+        let b: [u8; 15] = [1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4, 0, 0, 0, 0];
+        let c: [u8; 11] = [1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4];
+
+        assert_eq!(a, Cns::from_slice(&b).unwrap());
+        assert_eq!(a, Cns::from_slice(&c).unwrap());
+        assert_eq!(
+            Cns([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 8]),
+            Cns::from_slice(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6]).unwrap()
+        );
+        assert_eq!(
+            Cns([8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1]),
+            Cns::from_slice(&[8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0]).unwrap()
+        );
+        assert_eq!(
+            Cns::from_slice(&[8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 2]),
+            Err(ParseCnsError::InvalidNumber)
+        );
+        assert_eq!(
+            Cns::from_slice(&[3, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4]),
+            Err(ParseCnsError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn as_bytes() {
+        let a: [u8; 15] = [8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1];
+        let b = Cns([8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1]);
+
+        assert_eq!(&a, b.as_bytes());
+    }
+
+    #[test]
+    fn kind() {
+        let a = Cns([1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4, 0, 0, 0, 0]);
+        let b = Cns([8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1]);
+
+        assert_eq!(CnsKind::Definitive, a.kind());
+        assert_eq!(CnsKind::Provisional, b.kind());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        for _ in 0..100 {
+            let a = Cns::generate();
+            let b = a.to_string().parse::<Cns>().unwrap();
+
+            assert_eq!(a, b);
+        }
+    }
+
+    #[test]
+    fn document() {
+        let a = Cns([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 1, 8]);
+        let b = Cns([8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1]);
+
+        assert_eq!(&[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6], a.base());
+        assert_eq!(&[0, 0, 1, 8], a.check_digits());
+        assert_eq!(
+            &[0, 0, 1, 8],
+            Cns::compute_check_digits(a.base()).unwrap().as_ref()
+        );
+        assert_eq!(&[1], b.check_digits());
+        assert_eq!(&[1], Cns::compute_check_digits(b.base()).unwrap().as_ref());
+        assert_eq!(
+            Err(ParseCnsError::InvalidNumber),
+            Cns::compute_check_digits(&[0; 11])
+        );
+        assert_eq!(Cns::FORMATTED_LEN, format!("{a}").len());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Cns("898 0011 6066 0001")"#;
+        let b = Cns([8, 9, 8, 0, 0, 1, 1, 6, 0, 6, 6, 0, 0, 0, 1]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = "170 3325 9504 0000";
+        let b = Cns([1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4, 0, 0, 0, 0]);
+
+        assert_eq!(a, format!("{b}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "898 0011 6066 0001".parse::<Cns>().unwrap();
+        let b = "898001160660001".parse::<Cns>().unwrap();
+
+        assert_eq!(a, b);
+        assert!("700 0000 0000 0005".parse::<Cns>().is_ok());
+        assert!("100 0000 0006 0018".parse::<Cns>().is_ok());
+        assert_eq!("".parse::<Cns>(), Err(ParseCnsError::Empty));
+        assert_eq!(
+            "898.0011.6066.0001".parse::<Cns>(),
+            Err(ParseCnsError::InvalidCharacter('.', 3))
+        );
+        assert_eq!(
+            "898 0011 6066 0002".parse::<Cns>(),
+            Err(ParseCnsError::InvalidNumber)
+        );
+        // Valid sum, but not derived from the PIS
+        assert_eq!(
+            "100 0000 0006 0050".parse::<Cns>(),
+            Err(ParseCnsError::InvalidNumber)
+        );
+        assert_eq!(
+            "000 0000 0000 0000".parse::<Cns>(),
+            Err(ParseCnsError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let cns_str = "898 0011 6066 0001";
+        let cns = Cns::from_str(cns_str).unwrap();
+        serde_test::assert_tokens(&cns, &[serde_test::Token::Str(cns_str)]);
+    }
+}
@@ -14,6 +14,11 @@ use core::convert::TryFrom;
 use core::fmt::{self, Write};
 use core::str::FromStr;
 
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+use crate::explain::Explanation;
+use crate::suspicion::{self, Suspicion};
+
 #[cfg(feature = "rand")]
 use rand::{
     Rng,
@@ -23,6 +28,19 @@ use rand::{
 #[cfg(feature = "serde")]
 use serde::*;
 
+// Applied from right to left; the second check digit includes the first one
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+// Used as examples in documentation, including this crate's
+const KNOWN_EXAMPLES: [Cpf; 1] = [Cpf([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 9])];
+
+// Widely used in tests and sandbox environments
+const KNOWN_TEST_NUMBERS: [Cpf; 3] = [
+    Cpf([0, 0, 0, 0, 0, 0, 0, 0, 1, 9, 1]),
+    Cpf([1, 1, 1, 4, 4, 4, 7, 7, 7, 3, 5]),
+    Cpf([5, 2, 9, 9, 8, 2, 2, 4, 7, 2, 5]),
+];
+
 /// An error which can be returned when parsing an [`Cpf`] number.
 #[derive(Debug, PartialEq, Eq)]
 #[non_exhaustive]
@@ -98,12 +116,12 @@ impl Cpf {
         }
 
         for i in 0..=1 {
-            let remainder = calc_remainder(numbers, i);
+            let expected = CHECKSUM.check_digit(&numbers[..9 + i]);
             let check_digit = numbers[9 + i];
 
             if slice.len() < 11 {
-                numbers[9 + i] = remainder; // check digit
-            } else if remainder != check_digit {
+                numbers[9 + i] = expected; // check digit
+            } else if expected != check_digit {
                 return Err(ParseCpfError::InvalidNumber);
             }
         }
@@ -126,6 +144,62 @@ impl Cpf {
         &self.0
     }
 
+    /// Explains the calculation of the check digits, step by step, even if they are invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Cpf;
+    ///
+    /// let explanation = Cpf::explain("123.456.789-10").expect("malformed CPF");
+    /// assert!(!explanation.is_valid());
+    /// println!("{explanation}");
+    /// ```
+    pub fn explain(s: &str) -> Result<Explanation, ParseCpfError> {
+        parse_numbers(s).map(|numbers| Explanation::new(CHECKSUM, &numbers))
+    }
+
+    /// Analyzes the number, returning the reasons to suspect that it is a placeholder or a fraud.
+    /// The patterns are checked on the base (without the check digits).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cpf, Suspicion};
+    ///
+    /// let cpf = "123.456.789-09".parse::<Cpf>().expect("invalid CPF");
+    /// assert!(cpf.suspicion().contains(Suspicion::KNOWN_EXAMPLE));
+    /// ```
+    #[inline]
+    pub fn suspicion(&self) -> Suspicion {
+        self.suspicion_with(&[])
+    }
+
+    /// Analyzes the number like [`Cpf::suspicion`], also checking if it is in the `known` list.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cpf, Suspicion};
+    ///
+    /// let blocked = ["529.982.247-25".parse::<Cpf>().expect("invalid CPF")];
+    /// let cpf = "529.982.247-25".parse::<Cpf>().expect("invalid CPF");
+    /// assert!(cpf.suspicion_with(&blocked).contains(Suspicion::LISTED));
+    /// ```
+    pub fn suspicion_with(&self, known: &[Self]) -> Suspicion {
+        let mut suspicion = suspicion::analyze(&self.0[..9]);
+        if KNOWN_EXAMPLES.contains(self) {
+            suspicion |= Suspicion::KNOWN_EXAMPLE;
+        }
+        if KNOWN_TEST_NUMBERS.contains(self) {
+            suspicion |= Suspicion::KNOWN_TEST_NUMBER;
+        }
+        if known.contains(self) {
+            suspicion |= Suspicion::LISTED;
+        }
+        suspicion
+    }
+
     /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
     /// To use a different generator, instantiate the generator directly.
     ///
@@ -143,6 +217,49 @@ impl Cpf {
     }
 }
 
+impl Document for Cpf {
+    const NAME: &'static str = "CPF";
+    const LEN: usize = 11;
+    const FORMATTED_LEN: usize = 14;
+
+    type Error = ParseCpfError;
+    type CheckDigits = [u8; 2];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..9]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[9..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 9) {
+            return Err(ParseCpfError::InvalidNumber);
+        }
+
+        let mut numbers = [0; 11];
+        numbers[..9].copy_from_slice(base);
+        for i in 0..=1 {
+            numbers[9 + i] = CHECKSUM.check_digit(&numbers[..9 + i]);
+        }
+
+        Ok([numbers[9], numbers[9 + 1]])
+    }
+}
+
 impl AsRef<[u8]> for Cpf {
     #[inline]
     fn as_ref(&self) -> &[u8] {
@@ -199,37 +316,7 @@ impl FromStr for Cpf {
     type Err = ParseCpfError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut numbers = [0; 11];
-
-        if s.is_empty() {
-            return Err(ParseCpfError::Empty);
-        }
-
-        // Checks for invalid symbols and converts numbers to integers
-        let mut i = 0;
-        let mut has_dot = false;
-        for (offset, ch) in s.chars().enumerate() {
-            match (ch, offset) {
-                ('0'..='9', _) => {
-                    if i < 11 {
-                        // SAFETY: Digit already matched
-                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
-                        i += 1;
-                    } else {
-                        return Err(ParseCpfError::InvalidNumber);
-                    }
-                }
-                ('.', 3 | 7) => has_dot = true,
-                ('-' | '/', 11) if has_dot => continue,
-                ('-' | '/', 9) if !has_dot => continue,
-                _ => return Err(ParseCpfError::InvalidCharacter(ch, offset)),
-            }
-        }
-
-        // Checks the length
-        if i != 11 {
-            return Err(ParseCpfError::InvalidNumber);
-        }
+        let numbers = parse_numbers(s)?;
 
         // Checks for repeated numbers
         let first_number = numbers[0];
@@ -238,10 +325,10 @@ impl FromStr for Cpf {
         }
 
         for i in 0..=1 {
-            let remainder = calc_remainder(numbers, i);
+            let expected = CHECKSUM.check_digit(&numbers[..9 + i]);
             let check_digit = numbers[9 + i];
 
-            if remainder != check_digit {
+            if expected != check_digit {
                 return Err(ParseCpfError::InvalidNumber);
             }
         }
@@ -259,7 +346,7 @@ impl Distribution<Cpf> for StandardUniform {
         }
 
         for i in 0..=1 {
-            numbers[9 + i] = calc_remainder(numbers, i); // check digit
+            numbers[9 + i] = CHECKSUM.check_digit(&numbers[..9 + i]); // check digit
         }
 
         Cpf(numbers)
@@ -268,56 +355,55 @@ impl Distribution<Cpf> for StandardUniform {
 
 #[cfg(feature = "serde")]
 impl Serialize for Cpf {
+    #[inline]
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        #[cfg(not(feature = "std"))]
-        use crate::alloc::string::ToString;
-
-        serializer.serialize_str(&self.to_string())
+        document::serialize(self, serializer)
     }
 }
 
 #[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for Cpf {
+    #[inline]
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        struct CpfStringVisitor;
-
-        impl<'vi> de::Visitor<'vi> for CpfStringVisitor {
-            type Value = Cpf;
+        document::deserialize(deserializer)
+    }
+}
 
-            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                write!(formatter, "a CPF string")
-            }
+/// Converts the numbers of a string to integers, checking the separators and the length.
+fn parse_numbers(s: &str) -> Result<[u8; 11], ParseCpfError> {
+    let mut numbers = [0; 11];
 
-            fn visit_str<E: de::Error>(self, value: &str) -> Result<Cpf, E> {
-                value.parse().map_err(E::custom)
-            }
+    if s.is_empty() {
+        return Err(ParseCpfError::Empty);
+    }
 
-            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Cpf, E> {
-                Cpf::try_from(value).map_err(E::custom)
+    // Checks for invalid symbols and converts numbers to integers
+    let mut i = 0;
+    let mut has_dot = false;
+    for (offset, ch) in s.chars().enumerate() {
+        match (ch, offset) {
+            ('0'..='9', _) => {
+                if i < 11 {
+                    // SAFETY: Digit already matched
+                    numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                    i += 1;
+                } else {
+                    return Err(ParseCpfError::InvalidNumber);
+                }
             }
+            ('.', 3 | 7) => has_dot = true,
+            ('-' | '/', 11) if has_dot => continue,
+            ('-' | '/', 9) if !has_dot => continue,
+            _ => return Err(ParseCpfError::InvalidCharacter(ch, offset)),
         }
-
-        deserializer.deserialize_str(CpfStringVisitor)
     }
-}
 
-#[inline]
-fn calc_remainder(numbers: impl IntoIterator<Item = u8>, i: usize) -> u8 {
-    let remainder = numbers
-        .into_iter()
-        // Includes the first check digit in the second iteration
-        .take(9 + i)
-        // 10, 9, 8, ... 3, 2; and after: 11, 10, 9, 8, ... 3, 2
-        .zip((2..=10 + i).rev())
-        .map(|(x, y)| u32::from(x) * y as u32)
-        .sum::<u32>()
-        * 10
-        % 11;
-
-    match remainder {
-        10 | 11 => 0,
-        _ => remainder as u8,
+    // Checks the length
+    if i != 11 {
+        return Err(ParseCpfError::InvalidNumber);
     }
+
+    Ok(numbers)
 }
 
 #[cfg(test)]
@@ -326,6 +412,7 @@ mod tests {
     use alloc::format;
 
     use super::*;
+    use crate::DisplayStyle;
 
     #[test]
     fn from_slice() {
@@ -354,6 +441,27 @@ mod tests {
         assert_eq!(a, b);
     }
 
+    #[test]
+    fn document() {
+        let a = Cpf([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 9]);
+
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8, 9], a.base());
+        assert_eq!(&[0, 9], a.check_digits());
+        assert_eq!(
+            Ok([0, 9]),
+            Cpf::compute_check_digits(&[1, 2, 3, 4, 5, 6, 7, 8, 9])
+        );
+        assert_eq!(
+            Err(ParseCpfError::InvalidNumber),
+            Cpf::compute_check_digits(&[1, 2, 3])
+        );
+        assert_eq!(Cpf::FORMATTED_LEN, format!("{a}").len());
+        assert_eq!(
+            "12345678909",
+            format!("{}", a.display_style(DisplayStyle::Digits))
+        );
+    }
+
     #[test]
     fn as_ref() {
         fn test_trait<T: AsRef<[u8]>>(b: T) {
@@ -429,6 +537,57 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explain() {
+        let a = Cpf::explain("123.456.789-10").unwrap();
+        let [first, second] = a.steps();
+
+        assert_eq!(9, first.terms().len());
+        assert_eq!(10, first.terms()[0].weight());
+        assert_eq!(18, first.terms()[8].product());
+        assert_eq!(210, first.sum());
+        assert_eq!(1, first.remainder());
+        assert_eq!(10, first.complement());
+        assert_eq!(0, first.expected());
+        assert_eq!(1, first.found());
+        assert_eq!(10, second.terms().len());
+        assert_eq!(257, second.sum());
+        assert_eq!(7, second.expected());
+        assert!(!a.is_valid());
+        assert!(format!("{a}").contains("Check digit: 0 (found 1, mismatch)"));
+        assert!(format!("{a}").contains("Complement: 11 - 1 = 10 → 0"));
+        assert!(Cpf::explain("123.456.789-09").unwrap().is_valid());
+
+        // Repeated numbers
+        let b = Cpf::explain("111.111.111-11").unwrap();
+        assert!(b.steps().iter().all(|step| step.is_valid()));
+        assert!(b.is_repeated());
+        assert!(!b.is_valid());
+        assert!(format!("{b}").contains("Repeated numbers: rejected"));
+        assert!(!Cpf::explain("123.456.789-09").unwrap().is_repeated());
+        assert_eq!(Cpf::explain(""), Err(ParseCpfError::Empty));
+    }
+
+    #[test]
+    fn suspicion() {
+        for known in KNOWN_EXAMPLES.iter().chain(&KNOWN_TEST_NUMBERS) {
+            assert_eq!(Ok(*known), Cpf::try_from(known.as_bytes()));
+        }
+
+        let a = Cpf([1, 1, 1, 4, 4, 4, 7, 7, 7, 3, 5]);
+        assert_eq!(Suspicion::KNOWN_TEST_NUMBER, a.suspicion());
+        assert_eq!(
+            Suspicion::KNOWN_TEST_NUMBER | Suspicion::LISTED,
+            a.suspicion_with(&[a])
+        );
+        assert!(
+            Cpf::from_slice(&[3, 8, 2, 6, 1, 9, 4, 7, 5])
+                .unwrap()
+                .suspicion()
+                .is_empty()
+        );
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serialize() {
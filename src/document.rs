@@ -0,0 +1,173 @@
+// document.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::fmt;
+use core::hash::Hash;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+/// The style used to display a document number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DisplayStyle {
+    /// With separators, as in `123.456.789-09`.
+    #[default]
+    Formatted,
+    /// Only the digits, as in `12345678909`.
+    Digits,
+}
+
+/// An identifier number with check digits, such as [`Cpf`](crate::Cpf) and
+/// [`Cnpj`](crate::Cnpj).
+///
+/// # Examples
+///
+/// Generic code over any document type:
+///
+/// ```rust
+/// use brids::{Cnpj, Cpf, DisplayStyle, Document};
+///
+/// fn normalize<T: Document>(input: &str) -> Option<String> {
+///     let document = input.parse::<T>().ok()?;
+///     Some(document.display_style(DisplayStyle::Digits).to_string())
+/// }
+///
+/// assert_eq!(Some("12345678909".into()), normalize::<Cpf>("123.456.789-09"));
+/// assert_eq!(Some("12345678000195".into()), normalize::<Cnpj>("12.345.678/0001-95"));
+/// ```
+pub trait Document:
+    Sized + Copy + Eq + Ord + Hash + fmt::Debug + fmt::Display + FromStr<Err = Self::Error>
+{
+    /// The name of the document, as in `"CPF"`.
+    const NAME: &'static str;
+
+    /// The number of digits, including the check digits.
+    const LEN: usize;
+
+    /// The length of the formatted number, including the separators.
+    const FORMATTED_LEN: usize;
+
+    /// The error returned when parsing a number fails, also used as [`FromStr::Err`].
+    type Error: core::error::Error;
+
+    /// The check digits returned by [`Document::compute_check_digits`].
+    type CheckDigits: AsRef<[u8]>;
+
+    /// Parses a byte slice of numbers, guessing the missing parts.
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error>;
+
+    /// Returns a byte slice of the numbers.
+    fn digits(&self) -> &[u8];
+
+    /// Returns the numbers without the check digits.
+    fn base(&self) -> &[u8];
+
+    /// Returns the check digits.
+    fn check_digits(&self) -> &[u8];
+
+    /// Computes the check digits of the numbers without them.
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error>;
+
+    /// Returns an object that displays the number with the style.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cpf, DisplayStyle, Document};
+    ///
+    /// let cpf = "123.456.789-09".parse::<Cpf>().expect("invalid CPF");
+    /// assert_eq!("12345678909", cpf.display_style(DisplayStyle::Digits).to_string());
+    /// ```
+    #[inline]
+    fn display_style(&self, style: DisplayStyle) -> Styled<'_, Self> {
+        Styled {
+            document: self,
+            style,
+        }
+    }
+}
+
+/// Displays a document number with a [`DisplayStyle`].
+///
+/// This struct is created by [`Document::display_style`].
+#[derive(Clone, Copy, Debug)]
+pub struct Styled<'a, T> {
+    document: &'a T,
+    style: DisplayStyle,
+}
+
+impl<T: Document> fmt::Display for Styled<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.style {
+            DisplayStyle::Formatted => fmt::Display::fmt(self.document, f),
+            DisplayStyle::Digits => {
+                for number in self.document.digits() {
+                    number.fmt(f)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Validates the length and the range of the numbers.
+pub(crate) fn check_base(base: &[u8], len: usize) -> bool {
+    base.len() == len && base.iter().all(|&x| x <= 9)
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn serialize<T: Document, S: Serializer>(
+    document: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    #[cfg(not(feature = "std"))]
+    use crate::alloc::string::ToString;
+
+    serializer.serialize_str(&document.to_string())
+}
+
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Document,
+    T::Err: fmt::Display,
+    D: Deserializer<'de>,
+{
+    struct DocumentStringVisitor<T>(PhantomData<T>);
+
+    impl<'vi, T> de::Visitor<'vi> for DocumentStringVisitor<T>
+    where
+        T: Document,
+        T::Err: fmt::Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a {} string", T::NAME)
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<T, E> {
+            value.parse().map_err(E::custom)
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<T, E> {
+            T::from_digits(value).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(DocumentStringVisitor(PhantomData))
+}
@@ -0,0 +1,205 @@
+// explain.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::fmt;
+
+use crate::checksum::Mod11;
+
+const MAX_TERMS: usize = 13;
+
+/// A number and its weight, as a term of a weighted sum.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WeightedTerm {
+    digit: u8,
+    weight: u8,
+}
+
+impl WeightedTerm {
+    /// Returns the number.
+    #[inline]
+    pub fn digit(&self) -> u8 {
+        self.digit
+    }
+
+    /// Returns the weight.
+    #[inline]
+    pub fn weight(&self) -> u8 {
+        self.weight
+    }
+
+    /// Returns the number multiplied by the weight.
+    #[inline]
+    pub fn product(&self) -> u32 {
+        u32::from(self.digit) * u32::from(self.weight)
+    }
+}
+
+/// The calculation of a single check digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CheckDigitStep {
+    terms: [WeightedTerm; MAX_TERMS],
+    len: usize,
+    sum: u32,
+    expected: u8,
+    found: u8,
+}
+
+impl CheckDigitStep {
+    fn new(checksum: Mod11, numbers: &[u8], found: u8) -> Self {
+        let mut terms = [WeightedTerm::default(); MAX_TERMS];
+        for (i, (term, &digit)) in terms.iter_mut().zip(numbers).enumerate() {
+            *term = WeightedTerm {
+                digit,
+                weight: checksum.weight(i, numbers.len()),
+            };
+        }
+
+        let sum = checksum.sum(numbers);
+        Self {
+            terms,
+            len: numbers.len(),
+            sum,
+            expected: checksum.digit_from_sum(sum),
+            found,
+        }
+    }
+
+    /// Returns the terms of the weighted sum, one for each number.
+    #[inline]
+    pub fn terms(&self) -> &[WeightedTerm] {
+        &self.terms[..self.len]
+    }
+
+    /// Returns the weighted sum.
+    #[inline]
+    pub fn sum(&self) -> u32 {
+        self.sum
+    }
+
+    /// Returns the remainder of the weighted sum divided by 11.
+    #[inline]
+    pub fn remainder(&self) -> u32 {
+        self.sum % 11
+    }
+
+    /// Returns `11` minus the remainder, before `10` and `11` are mapped to `0`.
+    #[inline]
+    pub fn complement(&self) -> u32 {
+        11 - self.remainder()
+    }
+
+    /// Returns the check digit resulting from the calculation.
+    #[inline]
+    pub fn expected(&self) -> u8 {
+        self.expected
+    }
+
+    /// Returns the check digit found in the input.
+    #[inline]
+    pub fn found(&self) -> u8 {
+        self.found
+    }
+
+    /// Checks if the check digit found in the input is the expected one.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        self.expected == self.found
+    }
+}
+
+/// A step-by-step explanation of the check digits of a number, as returned by
+/// [`Cpf::explain`](crate::Cpf::explain) and [`Cnpj::explain`](crate::Cnpj::explain).
+///
+/// The [`Display`](fmt::Display) implementation renders the calculation as a table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Explanation {
+    steps: [CheckDigitStep; 2],
+    repeated: bool,
+}
+
+impl Explanation {
+    /// Explains the two check digits at the end of the numbers.
+    pub(crate) fn new(checksum: Mod11, numbers: &[u8]) -> Self {
+        let len = numbers.len();
+        let first_number = numbers[0];
+        Self {
+            steps: [
+                CheckDigitStep::new(checksum, &numbers[..len - 2], numbers[len - 2]),
+                CheckDigitStep::new(checksum, &numbers[..len - 1], numbers[len - 1]),
+            ],
+            repeated: numbers.iter().all(|&x| x == first_number),
+        }
+    }
+
+    /// Returns the calculation of each check digit.
+    #[inline]
+    pub fn steps(&self) -> &[CheckDigitStep; 2] {
+        &self.steps
+    }
+
+    /// Checks if all numbers are repeated, as in `111.111.111-11`. These numbers are rejected even
+    /// when the check digits match.
+    #[inline]
+    pub fn is_repeated(&self) -> bool {
+        self.repeated
+    }
+
+    /// Checks if all check digits found in the input are the expected ones, and the numbers are
+    /// not repeated.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        !self.repeated && self.steps.iter().all(CheckDigitStep::is_valid)
+    }
+}
+
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, step) in self.steps.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+
+            writeln!(f, "Check digit {}", i + 1)?;
+            writeln!(f, "{:>6} {:>7} {:>8}", "Digit", "Weight", "Product")?;
+            for term in step.terms() {
+                writeln!(
+                    f,
+                    "{:>6} {:>7} {:>8}",
+                    term.digit(),
+                    term.weight(),
+                    term.product()
+                )?;
+            }
+            writeln!(f, "Sum: {}", step.sum())?;
+            writeln!(f, "Remainder: {} % 11 = {}", step.sum(), step.remainder())?;
+            writeln!(
+                f,
+                "Complement: 11 - {} = {} → {}",
+                step.remainder(),
+                step.complement(),
+                step.expected()
+            )?;
+            writeln!(
+                f,
+                "Check digit: {} (found {}, {})",
+                step.expected(),
+                step.found(),
+                if step.is_valid() { "ok" } else { "mismatch" }
+            )?;
+        }
+        if self.repeated {
+            writeln!(f)?;
+            writeln!(f, "Repeated numbers: rejected")?;
+        }
+        Ok(())
+    }
+}
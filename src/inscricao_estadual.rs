@@ -0,0 +1,584 @@
+// inscricao_estadual.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::fmt::{self, Write};
+
+use crate::checksum::{LUHN, Mod10, Mod11};
+use crate::uf::Uf;
+
+// 9, 8, 7, 6, 5, 4, 3, 2, applied from right to left; used by most states
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+// 7, 6, 5, 4, 3, 2 (PR and RJ)
+const SHORT_CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7]);
+
+// 10, 9, ..., 2; the weighted sum is multiplied by 10 (AL and RN)
+const TIMES_TEN_CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+// 9, 8, ..., 2 (BA, modulo 10 variant)
+const BA_CHECKSUM: Mod10 = Mod10::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+// 3, 2, 11, 10, ..., 2 (MG, second check digit)
+const MG_CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+
+// 5, 4, 3, 2, 1, 9, 8, ..., 2; 10 and 11 become 0 and 1 (PE, older CACEPE numbers)
+const PE_CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 1]).map_eleven(1);
+
+// 10 and 11 become 0 and 1 (RO)
+const RO_CHECKSUM: Mod11 = CHECKSUM.map_eleven(1);
+
+// 1, 3, 4, 5, 6, 7, 8, 10 (SP, first check digit)
+const SP_FIRST_CHECKSUM: Mod11 = Mod11::new(&[10, 8, 7, 6, 5, 4, 3, 1]).remainder();
+
+// 3, 2, 10, 9, ..., 2 (SP, second check digit)
+const SP_SECOND_CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9, 10]).remainder();
+
+/// An error which can be returned when parsing an [`InscricaoEstadual`].
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseInscricaoEstadualError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseInscricaoEstadualError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseInscricaoEstadualError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid inscrição estadual number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseInscricaoEstadualError {}
+
+/// A valid _inscrição estadual_ (state tax registration) number, or `ISENTO` (exempt).
+///
+/// Each state has its own length, mask and check digit algorithm, so the state must be known to
+/// parse a number; use [`InscricaoEstadual::detect`] otherwise. Parsing recognizes numbers with
+/// or without separators (dot, minus, slash and space), and the `P` prefix of rural producers in
+/// SP.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::{InscricaoEstadual, Uf};
+///
+/// let ie = InscricaoEstadual::parse(Uf::Sp, "110042490114").expect("invalid IE");
+/// assert_eq!("110.042.490.114", ie.to_string());
+///
+/// let ie = InscricaoEstadual::parse(Uf::Mg, "isento").expect("invalid IE");
+/// assert!(ie.is_isento());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct InscricaoEstadual {
+    uf: Uf,
+    numbers: [u8; 14],
+    len: u8,
+    rural: bool,
+}
+
+impl InscricaoEstadual {
+    /// Parses a string as an inscrição estadual of the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{InscricaoEstadual, Uf};
+    ///
+    /// match InscricaoEstadual::parse(Uf::Ba, "123456-63") {
+    ///     Ok(ie) => println!("{ie} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn parse(uf: Uf, s: &str) -> Result<Self, ParseInscricaoEstadualError> {
+        let mut numbers = [0; 14];
+
+        if s.is_empty() {
+            return Err(ParseInscricaoEstadualError::Empty);
+        }
+
+        if s.eq_ignore_ascii_case("ISENTO") {
+            return Ok(Self::isento(uf));
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        let mut rural = false;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 14 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseInscricaoEstadualError::InvalidNumber);
+                    }
+                }
+                ('P' | 'p', 0) if uf == Uf::Sp => rural = true,
+                ('.' | '-' | '/' | ' ', _) => continue,
+                _ => return Err(ParseInscricaoEstadualError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        Self::new(uf, &numbers[..i], rural)
+    }
+
+    /// Parses a byte slice of numbers as an inscrição estadual of the state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{InscricaoEstadual, Uf};
+    ///
+    /// match InscricaoEstadual::from_slice(Uf::Rj, &[9, 9, 9, 9, 9, 9, 9, 3]) {
+    ///     Ok(ie) => println!("{ie} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(uf: Uf, slice: &[u8]) -> Result<Self, ParseInscricaoEstadualError> {
+        if slice.is_empty() {
+            return Err(ParseInscricaoEstadualError::Empty);
+        }
+
+        // 0..=9
+        if slice.len() > 14 || slice.iter().any(|&x| x > 9) {
+            return Err(ParseInscricaoEstadualError::InvalidNumber);
+        }
+
+        Self::new(uf, slice, false)
+    }
+
+    /// Returns an iterator over the states where the string is a valid inscrição estadual. The
+    /// `ISENTO` string is valid in all the states.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{InscricaoEstadual, Uf};
+    ///
+    /// let mut ufs = InscricaoEstadual::detect("123456-63");
+    /// assert_eq!(Some(Uf::Ba), ufs.next());
+    /// assert_eq!(None, ufs.next());
+    /// ```
+    pub fn detect(s: &str) -> impl Iterator<Item = Uf> + '_ {
+        Uf::ALL
+            .into_iter()
+            .filter(move |&uf| Self::parse(uf, s).is_ok())
+    }
+
+    /// Returns the state of the number.
+    #[inline]
+    pub fn uf(&self) -> Uf {
+        self.uf
+    }
+
+    /// Returns `true` if the company is exempt (`ISENTO`).
+    #[inline]
+    pub fn is_isento(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the number belongs to a rural producer in SP (`P` prefix).
+    #[inline]
+    pub fn is_rural(&self) -> bool {
+        self.rural
+    }
+
+    /// Returns a byte slice of the numbers, which is empty if the company is exempt.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{InscricaoEstadual, Uf};
+    ///
+    /// let ie = InscricaoEstadual::parse(Uf::Rj, "99.999.99-3").expect("invalid IE");
+    /// assert_eq!(&[9, 9, 9, 9, 9, 9, 9, 3], ie.as_bytes());
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.numbers[..self.len as usize]
+    }
+
+    fn isento(uf: Uf) -> Self {
+        InscricaoEstadual {
+            uf,
+            numbers: [0; 14],
+            len: 0,
+            rural: false,
+        }
+    }
+
+    fn new(uf: Uf, slice: &[u8], rural: bool) -> Result<Self, ParseInscricaoEstadualError> {
+        let mut numbers = [0; 14];
+        let len = match uf {
+            // Left-padded with zeros
+            Uf::Mt if (9..=11).contains(&slice.len()) => 11,
+            _ => slice.len(),
+        };
+        numbers[len - slice.len()..len].copy_from_slice(slice);
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers[..len].iter().all(|&x| x == first_number) {
+            return Err(ParseInscricaoEstadualError::InvalidNumber);
+        }
+
+        if !is_valid(uf, &numbers[..len], rural) {
+            return Err(ParseInscricaoEstadualError::InvalidNumber);
+        }
+
+        Ok(InscricaoEstadual {
+            uf,
+            numbers,
+            len: len as u8,
+            rural,
+        })
+    }
+
+    /// Returns the mask of the number, where `#` is a digit.
+    fn mask(&self) -> &'static str {
+        use Uf::*;
+        match (self.uf, self.len) {
+            (Ac | Df, _) => "##.###.###/###-##",
+            (Al | Ap | Ma | Pi, _) => "#########",
+            (Am | Go | Ms, _) => "##.###.###-#",
+            (Ba, 8) => "######-##",
+            (Ba, _) => "#######-##",
+            (Ce | Pb | Rr | Se, _) => "########-#",
+            (Es, _) => "###.###.##-#",
+            (Mg, _) => "###.###.###/####",
+            (Mt, _) => "##########-#",
+            (Pa, _) => "##-######-#",
+            (Pe, 9) => "#######-##",
+            (Pe, _) => "##.#.###.#######-#",
+            (Pr, _) => "###.#####-##",
+            (Rj, _) => "##.###.##-#",
+            (Rn, 9) => "##.###.###-#",
+            (Rn, _) => "##.#.###.###-#",
+            (Ro, 9) => "###.#####-#",
+            (Ro, _) => "#############-#",
+            (Rs, _) => "###/#######",
+            (Sc, _) => "###.###.###",
+            (Sp, _) if self.rural => "P-########.#/###",
+            (Sp, _) => "###.###.###.###",
+            (To, 9) => "##.###.###-#",
+            (To, _) => "##.##.######-#",
+        }
+    }
+}
+
+impl AsRef<[u8]> for InscricaoEstadual {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::Debug for InscricaoEstadual {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "InscricaoEstadual({}, \"{self}\")", self.uf)
+    }
+}
+
+impl fmt::Display for InscricaoEstadual {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_isento() {
+            return f.write_str("ISENTO");
+        }
+
+        let mut numbers = self.as_bytes().iter();
+        for ch in self.mask().chars() {
+            match ch {
+                '#' => numbers.next().unwrap_or(&0).fmt(f)?,
+                _ => f.write_char(ch)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn is_valid(uf: Uf, numbers: &[u8], rural: bool) -> bool {
+    use Uf::*;
+    match (uf, numbers.len()) {
+        (Ac, 13) => numbers.starts_with(&[0, 1]) && has_two_check_digits(CHECKSUM, numbers),
+        (Al, 9) => numbers.starts_with(&[2, 4]) && times_ten_is_valid(numbers),
+        (Ap, 9) => numbers.starts_with(&[0, 3]) && ap_is_valid(numbers),
+        (Am | Ce | Es | Pb | Pi | Sc | Se, 9) => CHECKSUM.is_valid(numbers),
+        (Ba, 8 | 9) => ba_is_valid(numbers),
+        (Df, 13) => numbers.starts_with(&[0, 7]) && has_two_check_digits(CHECKSUM, numbers),
+        (Go, 9) => go_is_valid(numbers),
+        (Ma, 9) => numbers.starts_with(&[1, 2]) && CHECKSUM.is_valid(numbers),
+        (Mg, 13) => mg_is_valid(numbers),
+        (Ms, 9) => {
+            (numbers.starts_with(&[2, 8]) || numbers.starts_with(&[5, 0]))
+                && CHECKSUM.is_valid(numbers)
+        }
+        (Mt, 11) => CHECKSUM.is_valid(numbers),
+        (Pa, 9) => numbers.starts_with(&[1, 5]) && CHECKSUM.is_valid(numbers),
+        (Pe, 9) => has_two_check_digits(CHECKSUM, numbers),
+        (Pe, 14) => PE_CHECKSUM.is_valid(numbers),
+        (Pr, 10) => has_two_check_digits(SHORT_CHECKSUM, numbers),
+        (Rj, 8) => SHORT_CHECKSUM.is_valid(numbers),
+        (Rn, 9 | 10) => numbers.starts_with(&[2, 0]) && times_ten_is_valid(numbers),
+        (Ro, 9) => RO_CHECKSUM.is_valid(&numbers[3..]), // Ignores the municipality
+        (Ro, 14) => RO_CHECKSUM.is_valid(numbers),
+        (Rr, 9) => numbers.starts_with(&[2, 4]) && rr_is_valid(numbers),
+        (Rs, 10) => CHECKSUM.is_valid(numbers),
+        (Sp, 12) if rural => SP_FIRST_CHECKSUM.is_valid(&numbers[..9]),
+        (Sp, 12) => {
+            SP_FIRST_CHECKSUM.is_valid(&numbers[..9]) && SP_SECOND_CHECKSUM.is_valid(numbers)
+        }
+        (To, 9) => CHECKSUM.is_valid(numbers),
+        (To, 11) => to_is_valid(numbers),
+        _ => false,
+    }
+}
+
+/// Checks the last two numbers, each one computed over the preceding numbers.
+fn has_two_check_digits(checksum: Mod11, numbers: &[u8]) -> bool {
+    checksum.is_valid(&numbers[..numbers.len() - 1]) && checksum.is_valid(numbers)
+}
+
+fn to_u32(numbers: &[u8]) -> u32 {
+    numbers.iter().fold(0, |acc, &x| acc * 10 + u32::from(x))
+}
+
+// AL and RN
+fn times_ten_is_valid(numbers: &[u8]) -> bool {
+    let (&check_digit, base) = numbers.split_last().unwrap();
+    let sum = TIMES_TEN_CHECKSUM.sum(base);
+    (sum * 10 % 11 % 10) as u8 == check_digit
+}
+
+fn ap_is_valid(numbers: &[u8]) -> bool {
+    let (&check_digit, base) = numbers.split_last().unwrap();
+
+    // Depends on the range of the number
+    let (p, d) = match to_u32(base) {
+        ..=3_017_000 => (5, 0),
+        3_017_001..=3_019_022 => (9, 1),
+        _ => (0, 0),
+    };
+
+    CHECKSUM
+        .map_eleven(d)
+        .digit_from_sum(CHECKSUM.sum(base) + p)
+        == check_digit
+}
+
+fn ba_is_valid(numbers: &[u8]) -> bool {
+    // The second number of 9-digit numbers, or the first one otherwise
+    let selector = numbers[numbers.len() - 8];
+    let check_digit = |base: &[u8]| match selector {
+        6 | 7 | 9 => CHECKSUM.check_digit(base),
+        _ => BA_CHECKSUM.check_digit(base),
+    };
+
+    // The last number is computed first, and then used to compute the other one
+    let len = numbers.len() - 2;
+    let mut base = [0; 8];
+    base[..len].copy_from_slice(&numbers[..len]);
+    base[len] = check_digit(&base[..len]);
+    let first = check_digit(&base[..=len]);
+
+    numbers[len..] == [first, base[len]]
+}
+
+fn go_is_valid(numbers: &[u8]) -> bool {
+    if !matches!(numbers[..2], [1, 0 | 1 | 5] | [2, _]) {
+        return false;
+    }
+
+    match to_u32(&numbers[..8]) {
+        11_094_402 => numbers[8] <= 1, // Both check digits are accepted
+        10_103_105..=10_119_997 => CHECKSUM.map_ten(1).is_valid(numbers),
+        _ => CHECKSUM.is_valid(numbers),
+    }
+}
+
+fn mg_is_valid(numbers: &[u8]) -> bool {
+    // Inserts a zero after the municipality
+    let mut base = [0; 12];
+    base[..3].copy_from_slice(&numbers[..3]);
+    base[4..].copy_from_slice(&numbers[3..11]);
+
+    LUHN.check_digit(&base) == numbers[11] && MG_CHECKSUM.is_valid(numbers)
+}
+
+fn rr_is_valid(numbers: &[u8]) -> bool {
+    // 1, 2, ..., 8, applied from left to right
+    let sum: u32 = (1..)
+        .zip(&numbers[..8])
+        .map(|(weight, &x)| weight * u32::from(x))
+        .sum();
+    (sum % 9) as u8 == numbers[8]
+}
+
+fn to_is_valid(numbers: &[u8]) -> bool {
+    // The type of company, which is ignored
+    if !matches!(numbers[2..4], [0, 1..=3] | [9, 9]) {
+        return false;
+    }
+
+    let mut base = [0; 9];
+    base[..2].copy_from_slice(&numbers[..2]);
+    base[2..].copy_from_slice(&numbers[4..]);
+    CHECKSUM.is_valid(&base)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    const EXAMPLES: [(Uf, &str); 32] = [
+        (Uf::Ac, "01.004.823/001-12"),
+        (Uf::Al, "240000048"),
+        (Uf::Ap, "030123459"),
+        (Uf::Am, "04.341.484-2"),
+        (Uf::Ba, "123456-63"),
+        (Uf::Ba, "612345-57"),
+        (Uf::Ba, "1000003-06"),
+        (Uf::Ce, "06000001-5"),
+        (Uf::Df, "07.300.001/001-09"),
+        (Uf::Es, "082.560.01-3"),
+        (Uf::Go, "10.987.654-7"),
+        (Uf::Ma, "120000385"),
+        (Uf::Mt, "0013000001-9"),
+        (Uf::Ms, "28.315.359-8"),
+        (Uf::Mg, "062.307.904/0081"),
+        (Uf::Pa, "15-999999-5"),
+        (Uf::Pb, "06000001-5"),
+        (Uf::Pr, "123.45678-50"),
+        (Uf::Pe, "0321418-40"),
+        (Uf::Pe, "18.1.001.0000004-9"),
+        (Uf::Pi, "012345679"),
+        (Uf::Rj, "99.999.99-3"),
+        (Uf::Rn, "20.040.040-1"),
+        (Uf::Rn, "20.0.040.040-0"),
+        (Uf::Rs, "224/3658792"),
+        (Uf::Ro, "0000000062521-3"),
+        (Uf::Rr, "24006628-1"),
+        (Uf::Sc, "251.040.852"),
+        (Uf::Sp, "110.042.490.114"),
+        (Uf::Sp, "P-01100424.3/002"),
+        (Uf::Se, "27123456-3"),
+        (Uf::To, "29.01.022783-6"),
+    ];
+
+    #[test]
+    fn parse() {
+        for (uf, s) in EXAMPLES {
+            let a = InscricaoEstadual::parse(uf, s).unwrap();
+
+            assert_eq!(s, format!("{a}"));
+            assert_eq!(uf, a.uf());
+        }
+
+        assert!(InscricaoEstadual::parse(Uf::Ro, "101.62521-3").is_ok());
+        assert!(InscricaoEstadual::parse(Uf::Go, "11.094.402-1").is_ok());
+        assert!(InscricaoEstadual::parse(Uf::To, "29.022.783-6").is_ok());
+        assert!(InscricaoEstadual::parse(Uf::Mt, "130000019").is_ok());
+        assert_eq!(
+            InscricaoEstadual::parse(Uf::Sp, ""),
+            Err(ParseInscricaoEstadualError::Empty)
+        );
+        assert_eq!(
+            InscricaoEstadual::parse(Uf::Rj, "P-01100424.3/002"),
+            Err(ParseInscricaoEstadualError::InvalidCharacter('P', 0))
+        );
+        assert_eq!(
+            InscricaoEstadual::parse(Uf::Sp, "110.042.490.115"),
+            Err(ParseInscricaoEstadualError::InvalidNumber)
+        );
+        assert_eq!(
+            InscricaoEstadual::parse(Uf::Sp, "000.000.000.000"),
+            Err(ParseInscricaoEstadualError::InvalidNumber)
+        );
+        assert_eq!(
+            InscricaoEstadual::parse(Uf::Ac, "07.300.001/001-09"),
+            Err(ParseInscricaoEstadualError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        let a = InscricaoEstadual::parse(Uf::Rj, "99.999.99-3").unwrap();
+
+        assert_eq!(
+            a,
+            InscricaoEstadual::from_slice(Uf::Rj, &[9, 9, 9, 9, 9, 9, 9, 3]).unwrap()
+        );
+        assert_eq!(
+            InscricaoEstadual::from_slice(Uf::Rj, &[9, 9, 9, 9, 9, 9, 9, 4]),
+            Err(ParseInscricaoEstadualError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn isento() {
+        let a = InscricaoEstadual::parse(Uf::Sp, "ISENTO").unwrap();
+        let b = InscricaoEstadual::parse(Uf::Sp, "Isento").unwrap();
+
+        assert_eq!(a, b);
+        assert!(a.is_isento());
+        assert!(a.as_bytes().is_empty());
+        assert_eq!("ISENTO", format!("{a}"));
+    }
+
+    #[test]
+    fn rural() {
+        let a = InscricaoEstadual::parse(Uf::Sp, "P011004243002").unwrap();
+        let b = InscricaoEstadual::parse(Uf::Sp, "110.042.490.114").unwrap();
+
+        assert!(a.is_rural());
+        assert!(!b.is_rural());
+    }
+
+    #[test]
+    fn detect() {
+        let a = InscricaoEstadual::detect("123456-63");
+        let b = InscricaoEstadual::detect("06000001-5");
+
+        assert!(a.eq([Uf::Ba]));
+        assert!(b.eq([
+            Uf::Am,
+            Uf::Ce,
+            Uf::Es,
+            Uf::Mt,
+            Uf::Pb,
+            Uf::Pi,
+            Uf::Sc,
+            Uf::Se,
+            Uf::To
+        ]));
+        assert_eq!(0, InscricaoEstadual::detect("12345").count());
+        assert_eq!(27, InscricaoEstadual::detect("ISENTO").count());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"InscricaoEstadual(SP, "110.042.490.114")"#;
+        let b = InscricaoEstadual::parse(Uf::Sp, "110042490114").unwrap();
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+}
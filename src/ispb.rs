@@ -0,0 +1,405 @@
+// ispb.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::cnpj::Cnpj;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+/// An error which can be returned when parsing an [`Ispb`].
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseIspbError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseIspbError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseIspbError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid ISPB"),
+        }
+    }
+}
+
+impl core::error::Error for ParseIspbError {}
+
+/// An ISPB (_Identificador do Sistema de Pagamentos Brasileiro_), which identifies a financial
+/// institution in the SPB and in PIX. It is the root of the institution's [`Cnpj`], so it has no
+/// check digit. Parsing recognizes codes without separators only.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ispb([u8; 8]);
+
+impl Ispb {
+    /// Parses a byte slice of numbers as an ISPB.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Ispb;
+    ///
+    /// match Ispb::from_slice(&[6, 0, 7, 0, 1, 1, 9, 0]) {
+    ///     Ok(ispb) => println!("{ispb} is a valid code."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseIspbError> {
+        let mut numbers = [0; 8];
+        match slice.len() {
+            0 => return Err(ParseIspbError::Empty),
+            8 => numbers.copy_from_slice(slice),
+            _ => return Err(ParseIspbError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseIspbError::InvalidNumber);
+        }
+
+        Ok(Ispb(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Ispb;
+    ///
+    /// let ispb = "60701190".parse::<Ispb>().expect("invalid ISPB");
+    /// let digits = ispb.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 8] {
+        &self.0
+    }
+
+    /// Returns the code as an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Ispb;
+    ///
+    /// let ispb = "00360305".parse::<Ispb>().expect("invalid ISPB");
+    /// assert_eq!(360305, ispb.code());
+    /// ```
+    pub fn code(&self) -> u32 {
+        self.0.iter().fold(0, |acc, &x| acc * 10 + u32::from(x))
+    }
+
+    /// Returns the CNPJ of the institution's headquarters (`0001`).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Cnpj, Ispb};
+    ///
+    /// let ispb = "00000000".parse::<Ispb>().expect("invalid ISPB");
+    /// assert_eq!(Ok(ispb.cnpj()), "00.000.000/0001-91".parse::<Cnpj>());
+    /// ```
+    pub fn cnpj(&self) -> Cnpj {
+        match Cnpj::from_slice(&self.0) {
+            Ok(cnpj) => cnpj,
+            Err(_) => unreachable!("numbers already checked"),
+        }
+    }
+
+    /// Generates a random code, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Ispb;
+    ///
+    /// let ispb = Ispb::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+
+    fn from_code(mut code: u32) -> Self {
+        let mut numbers = [0; 8];
+        for number in numbers.iter_mut().rev() {
+            *number = (code % 10) as u8;
+            code /= 10;
+        }
+        Ispb(numbers)
+    }
+}
+
+impl AsRef<[u8]> for Ispb {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Ispb> for [u8; 8] {
+    #[inline]
+    fn from(ispb: Ispb) -> [u8; 8] {
+        ispb.0
+    }
+}
+
+impl From<Ispb> for u32 {
+    #[inline]
+    fn from(ispb: Ispb) -> u32 {
+        ispb.code()
+    }
+}
+
+impl From<Cnpj> for Ispb {
+    fn from(cnpj: Cnpj) -> Ispb {
+        let mut numbers = [0; 8];
+        numbers.copy_from_slice(&cnpj.as_bytes()[..8]);
+        Ispb(numbers)
+    }
+}
+
+impl TryFrom<&[u8]> for Ispb {
+    type Error = ParseIspbError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 8]> for Ispb {
+    type Error = ParseIspbError;
+
+    #[inline]
+    fn try_from(value: &[u8; 8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<u32> for Ispb {
+    type Error = ParseIspbError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value > 99_999_999 {
+            return Err(ParseIspbError::InvalidNumber);
+        }
+
+        Ok(Self::from_code(value))
+    }
+}
+
+impl fmt::Debug for Ispb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Ispb(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Ispb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for number in &self.0 {
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Ispb {
+    type Err = ParseIspbError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 8];
+
+        if s.is_empty() {
+            return Err(ParseIspbError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match ch {
+                '0'..='9' => {
+                    if i < 8 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseIspbError::InvalidNumber);
+                    }
+                }
+                _ => return Err(ParseIspbError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 8 {
+            return Err(ParseIspbError::InvalidNumber);
+        }
+
+        Ok(Ispb(numbers))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Ispb> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Ispb {
+        let mut numbers = [0; 8];
+        for number in &mut numbers {
+            *number = rng.random_range(0..=9);
+        }
+
+        Ispb(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Ispb {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(not(feature = "std"))]
+        use crate::alloc::string::ToString;
+
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Ispb {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IspbStringVisitor;
+
+        impl de::Visitor<'_> for IspbStringVisitor {
+            type Value = Ispb;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "an ISPB string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Ispb, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Ispb, E> {
+                Ispb::from_slice(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(IspbStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Ispb([6, 0, 7, 0, 1, 1, 9, 0]);
+
+        assert_eq!(Ok(a), Ispb::from_slice(&a.0));
+        assert_eq!(Ispb::from_slice(&[]), Err(ParseIspbError::Empty));
+        assert_eq!(
+            Ispb::from_slice(&[6, 0, 7, 0, 1, 1, 9]),
+            Err(ParseIspbError::InvalidNumber)
+        );
+        assert_eq!(
+            Ispb::from_slice(&[6, 0, 7, 0, 1, 1, 9, 10]),
+            Err(ParseIspbError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn cnpj() {
+        let cnpj = "60.701.190/0001-04".parse::<Cnpj>().unwrap();
+        let a = Ispb::from(cnpj);
+
+        assert_eq!(Ispb([6, 0, 7, 0, 1, 1, 9, 0]), a);
+        assert_eq!(cnpj, a.cnpj());
+        assert_eq!(a, Ispb::from("60.701.190/4816-09".parse::<Cnpj>().unwrap()));
+    }
+
+    #[test]
+    fn code() {
+        let a = Ispb([0, 0, 3, 6, 0, 3, 0, 5]);
+
+        assert_eq!(360305, a.code());
+        assert_eq!(Ok(a), Ispb::try_from(360305));
+        assert_eq!(
+            Ispb::try_from(100_000_000),
+            Err(ParseIspbError::InvalidNumber)
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Ispb::generate();
+        let b = a.to_string().parse::<Ispb>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Ispb("00000000")"#;
+        let b = Ispb([0; 8]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = Ispb([6, 0, 7, 0, 1, 1, 9, 0]);
+
+        assert_eq!(Ok(a), "60701190".parse());
+        assert_eq!("".parse::<Ispb>(), Err(ParseIspbError::Empty));
+        assert_eq!(
+            "60.701.190".parse::<Ispb>(),
+            Err(ParseIspbError::InvalidCharacter('.', 2))
+        );
+        assert_eq!(
+            "6070119".parse::<Ispb>(),
+            Err(ParseIspbError::InvalidNumber)
+        );
+        assert_eq!(
+            "607011900".parse::<Ispb>(),
+            Err(ParseIspbError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let ispb_str = "60701190";
+        let ispb = Ispb::from_str(ispb_str).unwrap();
+        serde_test::assert_tokens(&ispb, &[serde_test::Token::Str(ispb_str)]);
+    }
+}
@@ -10,7 +10,7 @@
 //
 // SPDX-License-Identifier: (MIT OR Apache-2.0)
 
-//! Parse and generate random CPF and CNPJ, Brazil's ID numbers.
+//! Parse and generate random CPF, CNPJ and other Brazil's ID numbers.
 //!
 //! # Usage
 //!
@@ -112,8 +112,55 @@
 #[cfg(not(feature = "std"))]
 extern crate alloc;
 
+mod caepf;
+mod cep;
+pub mod checksum;
+mod cnh;
+mod cno;
 mod cnpj;
+mod cns;
 mod cpf;
+mod document;
+mod explain;
+mod inscricao_estadual;
+mod ispb;
+mod matricula_certidao;
+mod municipio;
+mod nire;
+mod nis;
+mod numero_beneficio;
+mod placa;
+mod processo_cnj;
+mod renavam;
+mod rg;
+mod suframa;
+mod suspicion;
+mod telefone;
+mod titulo;
+mod uf;
 
+pub use caepf::*;
+pub use cep::*;
+pub use cnh::*;
+pub use cno::*;
 pub use cnpj::*;
+pub use cns::*;
 pub use cpf::*;
+pub use document::*;
+pub use explain::*;
+pub use inscricao_estadual::*;
+pub use ispb::*;
+pub use matricula_certidao::*;
+pub use municipio::*;
+pub use nire::*;
+pub use nis::*;
+pub use numero_beneficio::*;
+pub use placa::*;
+pub use processo_cnj::*;
+pub use renavam::*;
+pub use rg::*;
+pub use suframa::*;
+pub use suspicion::*;
+pub use telefone::*;
+pub use titulo::*;
+pub use uf::*;
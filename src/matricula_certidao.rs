@@ -0,0 +1,549 @@
+// matricula_certidao.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 2, 3, ..., 10, 0, 1, 2, ..., applied from left to right; the same weights, applied from right
+// to left, serve both check digits
+const CHECKSUM: Mod11 = Mod11::new(&[9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 10])
+    .remainder()
+    .map_ten(1);
+
+/// An error which can be returned when parsing a [`MatriculaCertidao`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseMatriculaCertidaoError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseMatriculaCertidaoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseMatriculaCertidaoError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid matrícula number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseMatriculaCertidaoError {}
+
+/// The type of book of a [`MatriculaCertidao`] number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BookType {
+    /// Livro A, births
+    Nascimento = 1,
+    /// Livro B, marriages
+    Casamento,
+    /// Livro B Auxiliar, religious marriages with civil effects
+    CasamentoReligioso,
+    /// Livro C, deaths
+    Obito,
+    /// Livro C Auxiliar, stillbirths
+    Natimorto,
+    /// Livro D, marriage banns
+    Proclamas,
+    /// Livro E, other acts
+    Demais,
+    /// Livro E, emancipations
+    Emancipacao,
+    /// Livro E, interdictions
+    Interdicao,
+}
+
+impl BookType {
+    /// Returns the type of book with the code, if there is one.
+    pub const fn from_code(code: u8) -> Option<Self> {
+        use BookType::*;
+        match code {
+            1 => Some(Nascimento),
+            2 => Some(Casamento),
+            3 => Some(CasamentoReligioso),
+            4 => Some(Obito),
+            5 => Some(Natimorto),
+            6 => Some(Proclamas),
+            7 => Some(Demais),
+            8 => Some(Emancipacao),
+            9 => Some(Interdicao),
+            _ => None,
+        }
+    }
+
+    /// Returns the code, as in the 15th number of the matrícula.
+    #[inline]
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A valid _matrícula_ of a birth, marriage or death certificate, issued since 2010. Parsing
+/// recognizes numbers with or without separators (space).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MatriculaCertidao([u8; 32]);
+
+impl MatriculaCertidao {
+    /// Parses a byte slice of numbers as a matrícula, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::MatriculaCertidao;
+    ///
+    /// let numbers = [
+    ///     1, 0, 4, 5, 3, 9, 0, 1, 5, 5, 2, 0, 1, 3, 1, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 0, 1, 2,
+    ///     3, 2, 1,
+    /// ];
+    /// match MatriculaCertidao::from_slice(&numbers) {
+    ///     Ok(matricula) => println!("{matricula} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digits:
+    ///
+    /// ```rust
+    /// use brids::MatriculaCertidao;
+    ///
+    /// let numbers = [
+    ///     1, 0, 4, 5, 3, 9, 0, 1, 5, 5, 2, 0, 1, 3, 1, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 0, 1, 2,
+    ///     3,
+    /// ];
+    /// match MatriculaCertidao::from_slice(&numbers) {
+    ///     Ok(matricula) => println!("{matricula} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseMatriculaCertidaoError> {
+        let mut numbers = [0; 32];
+        match slice.len() {
+            0 => return Err(ParseMatriculaCertidaoError::Empty),
+            len @ (30 | 32) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseMatriculaCertidaoError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) || BookType::from_code(numbers[14]).is_none() {
+            return Err(ParseMatriculaCertidaoError::InvalidNumber);
+        }
+
+        let check_digits = calc_check_digits(&numbers[..30]);
+        if slice.len() == 30 {
+            numbers[30..].copy_from_slice(&check_digits);
+        } else if check_digits != numbers[30..] {
+            return Err(ParseMatriculaCertidaoError::InvalidNumber);
+        }
+
+        Ok(MatriculaCertidao(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::MatriculaCertidao;
+    ///
+    /// let matricula = "104539 01 55 2013 1 00012 021 0000123 21"
+    ///     .parse::<MatriculaCertidao>()
+    ///     .expect("invalid matrícula");
+    /// let digits = matricula.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    /// Returns the national code of the registry office (_CNS da serventia_).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::MatriculaCertidao;
+    ///
+    /// let matricula = "104539 01 55 2013 1 00012 021 0000123 21"
+    ///     .parse::<MatriculaCertidao>()
+    ///     .expect("invalid matrícula");
+    /// assert_eq!(104539, matricula.office());
+    /// ```
+    #[inline]
+    pub fn office(&self) -> u32 {
+        to_u32(&self.0[..6])
+    }
+
+    /// Returns the archive (_acervo_): `1` for the office's own archive, `2` for an incorporated
+    /// one.
+    #[inline]
+    pub fn archive(&self) -> u8 {
+        self.0[6] * 10 + self.0[7]
+    }
+
+    /// Returns the type of service, which is `55` for the civil registry of natural persons.
+    #[inline]
+    pub fn service(&self) -> u8 {
+        self.0[8] * 10 + self.0[9]
+    }
+
+    /// Returns the year of the registration.
+    #[inline]
+    pub fn year(&self) -> u16 {
+        to_u32(&self.0[10..14]) as u16
+    }
+
+    /// Returns the type of book.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{BookType, MatriculaCertidao};
+    ///
+    /// let matricula = "104539 01 55 2013 1 00012 021 0000123 21"
+    ///     .parse::<MatriculaCertidao>()
+    ///     .expect("invalid matrícula");
+    /// assert_eq!(BookType::Nascimento, matricula.book_type());
+    /// ```
+    pub fn book_type(&self) -> BookType {
+        match BookType::from_code(self.0[14]) {
+            Some(book_type) => book_type,
+            None => unreachable!("type of book already checked"),
+        }
+    }
+
+    /// Returns the number of the book.
+    #[inline]
+    pub fn book(&self) -> u32 {
+        to_u32(&self.0[15..20])
+    }
+
+    /// Returns the page (_folha_).
+    #[inline]
+    pub fn page(&self) -> u16 {
+        to_u32(&self.0[20..23]) as u16
+    }
+
+    /// Returns the term (_termo_).
+    #[inline]
+    pub fn term(&self) -> u32 {
+        to_u32(&self.0[23..30])
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::MatriculaCertidao;
+    ///
+    /// let matricula = MatriculaCertidao::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for MatriculaCertidao {
+    const NAME: &'static str = "matrícula";
+    const LEN: usize = 32;
+    const FORMATTED_LEN: usize = 40;
+
+    type Error = ParseMatriculaCertidaoError;
+    type CheckDigits = [u8; 2];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..30]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[30..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 30) {
+            return Err(ParseMatriculaCertidaoError::InvalidNumber);
+        }
+
+        Ok(calc_check_digits(base))
+    }
+}
+
+impl AsRef<[u8]> for MatriculaCertidao {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<MatriculaCertidao> for [u8; 32] {
+    #[inline]
+    fn from(matricula: MatriculaCertidao) -> [u8; 32] {
+        matricula.0
+    }
+}
+
+impl TryFrom<&[u8]> for MatriculaCertidao {
+    type Error = ParseMatriculaCertidaoError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 32]> for MatriculaCertidao {
+    type Error = ParseMatriculaCertidaoError;
+
+    #[inline]
+    fn try_from(value: &[u8; 32]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for MatriculaCertidao {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "MatriculaCertidao(\"{self}\")")
+    }
+}
+
+impl fmt::Display for MatriculaCertidao {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            if let 6 | 8 | 10 | 14 | 15 | 20 | 23 | 30 = i {
+                f.write_char(' ')?;
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for MatriculaCertidao {
+    type Err = ParseMatriculaCertidaoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 32];
+
+        if s.is_empty() {
+            return Err(ParseMatriculaCertidaoError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 32 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseMatriculaCertidaoError::InvalidNumber);
+                    }
+                }
+                (' ', 6 | 9 | 12 | 17 | 19 | 25 | 29 | 37) => continue,
+                _ => return Err(ParseMatriculaCertidaoError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 32 {
+            return Err(ParseMatriculaCertidaoError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+fn to_u32(numbers: &[u8]) -> u32 {
+    numbers.iter().fold(0, |acc, &x| acc * 10 + u32::from(x))
+}
+
+fn calc_check_digits(base: &[u8]) -> [u8; 2] {
+    let mut numbers = [0; 31];
+    numbers[..30].copy_from_slice(base);
+    numbers[30] = CHECKSUM.check_digit(base);
+    [numbers[30], CHECKSUM.check_digit(&numbers)]
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<MatriculaCertidao> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> MatriculaCertidao {
+        let mut numbers = [0; 32];
+        for number in &mut numbers[..30] {
+            *number = rng.random_range(0..=9);
+        }
+
+        // Archive, service, year and type of book
+        numbers[6..10].copy_from_slice(&[0, rng.random_range(1..=2), 5, 5]);
+        numbers[10..13].copy_from_slice(&[2, 0, rng.random_range(1..=2)]);
+        numbers[14] = rng.random_range(1..=9);
+
+        let check_digits = calc_check_digits(&numbers[..30]);
+        numbers[30..].copy_from_slice(&check_digits);
+
+        MatriculaCertidao(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for MatriculaCertidao {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for MatriculaCertidao {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    const NUMBERS: [u8; 32] = [
+        1, 0, 4, 5, 3, 9, 0, 1, 5, 5, 2, 0, 1, 3, 1, 0, 0, 0, 1, 2, 0, 2, 1, 0, 0, 0, 0, 1, 2, 3,
+        2, 1,
+    ];
+
+    #[test]
+    fn from_slice() {
+        let a = MatriculaCertidao(NUMBERS);
+
+        assert_eq!(a, MatriculaCertidao::from_slice(&NUMBERS).unwrap());
+        assert_eq!(a, MatriculaCertidao::from_slice(&NUMBERS[..30]).unwrap());
+
+        let mut b = NUMBERS;
+        b[31] = 2;
+        assert_eq!(
+            MatriculaCertidao::from_slice(&b),
+            Err(ParseMatriculaCertidaoError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let a = MatriculaCertidao(NUMBERS);
+
+        assert_eq!(104539, a.office());
+        assert_eq!(1, a.archive());
+        assert_eq!(55, a.service());
+        assert_eq!(2013, a.year());
+        assert_eq!(BookType::Nascimento, a.book_type());
+        assert_eq!(12, a.book());
+        assert_eq!(21, a.page());
+        assert_eq!(123, a.term());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = MatriculaCertidao::generate();
+        let b = a.to_string().parse::<MatriculaCertidao>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn document() {
+        let a = MatriculaCertidao(NUMBERS);
+
+        assert_eq!(&NUMBERS[..30], a.base());
+        assert_eq!(&[2, 1], a.check_digits());
+        assert_eq!(
+            Ok([2, 1]),
+            MatriculaCertidao::compute_check_digits(&NUMBERS[..30])
+        );
+        assert_eq!(MatriculaCertidao::FORMATTED_LEN, format!("{a}").len());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"MatriculaCertidao("104539 01 55 2013 1 00012 021 0000123 21")"#;
+        let b = MatriculaCertidao(NUMBERS);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "104539 01 55 2013 1 00012 021 0000123 21"
+            .parse::<MatriculaCertidao>()
+            .unwrap();
+        let b = "10453901552013100012021000012321"
+            .parse::<MatriculaCertidao>()
+            .unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(
+            "".parse::<MatriculaCertidao>(),
+            Err(ParseMatriculaCertidaoError::Empty)
+        );
+        assert_eq!(
+            "104539.01.55.2013.1.00012.021.0000123.21".parse::<MatriculaCertidao>(),
+            Err(ParseMatriculaCertidaoError::InvalidCharacter('.', 6))
+        );
+        assert_eq!(
+            "10453901552013000012021000012321".parse::<MatriculaCertidao>(),
+            Err(ParseMatriculaCertidaoError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let matricula_str = "104539 01 55 2013 1 00012 021 0000123 21";
+        let matricula = MatriculaCertidao::from_str(matricula_str).unwrap();
+        serde_test::assert_tokens(&matricula, &[serde_test::Token::Str(matricula_str)]);
+    }
+}
@@ -0,0 +1,469 @@
+// municipio.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::checksum::LUHN;
+use crate::document::{self, Document};
+use crate::uf::Uf;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// Municipalities created with a wrong check digit, kept by IBGE as is
+const EXCEPTIONS: [u32; 9] = [
+    2201919, 2201988, 2202251, 2611533, 3117836, 3152131, 4305871, 5203939, 5203962,
+];
+
+/// An error which can be returned when parsing a [`Municipio`] code.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseMunicipioError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseMunicipioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseMunicipioError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid municipality code"),
+        }
+    }
+}
+
+impl core::error::Error for ParseMunicipioError {}
+
+/// A valid IBGE municipality code, as in the `cMun` field of NF-e. Parsing recognizes codes
+/// without separators only.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Municipio([u8; 7]);
+
+impl Municipio {
+    /// Parses a byte slice of numbers as a municipality code, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Municipio;
+    ///
+    /// match Municipio::from_slice(&[3, 5, 5, 0, 3, 0, 8]) {
+    ///     Ok(municipio) => println!("{municipio} is a valid code."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::Municipio;
+    ///
+    /// match Municipio::from_slice(&[3, 5, 5, 0, 3, 0]) {
+    ///     Ok(municipio) => println!("{municipio} is a valid code."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseMunicipioError> {
+        let mut numbers = [0; 7];
+        match slice.len() {
+            0 => return Err(ParseMunicipioError::Empty),
+            len @ (6 | 7) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseMunicipioError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseMunicipioError::InvalidNumber);
+        }
+
+        if slice.len() == 6 {
+            numbers[6] = LUHN.check_digit(&numbers[..6]); // check digit
+        }
+
+        if !is_valid(&numbers) {
+            return Err(ParseMunicipioError::InvalidNumber);
+        }
+
+        Ok(Municipio(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Municipio;
+    ///
+    /// let municipio = "3550308".parse::<Municipio>().expect("invalid code");
+    /// let digits = municipio.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 7] {
+        &self.0
+    }
+
+    /// Returns the code as an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Municipio;
+    ///
+    /// let municipio = "3550308".parse::<Municipio>().expect("invalid code");
+    /// assert_eq!(3550308, municipio.code());
+    /// ```
+    pub fn code(&self) -> u32 {
+        self.0.iter().fold(0, |acc, &x| acc * 10 + u32::from(x))
+    }
+
+    /// Returns the state of the municipality.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Municipio, Uf};
+    ///
+    /// let municipio = "3550308".parse::<Municipio>().expect("invalid code");
+    /// assert_eq!(Uf::Sp, municipio.uf());
+    /// ```
+    pub fn uf(&self) -> Uf {
+        match Uf::from_ibge_code(self.0[0] * 10 + self.0[1]) {
+            Some(uf) => uf,
+            None => unreachable!("state already checked"),
+        }
+    }
+
+    /// Generates a random code, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Municipio;
+    ///
+    /// let municipio = Municipio::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+
+    fn from_code(mut code: u32) -> Self {
+        let mut numbers = [0; 7];
+        for number in numbers.iter_mut().rev() {
+            *number = (code % 10) as u8;
+            code /= 10;
+        }
+        Municipio(numbers)
+    }
+}
+
+impl Document for Municipio {
+    const NAME: &'static str = "municipality code";
+    const LEN: usize = 7;
+    const FORMATTED_LEN: usize = 7;
+
+    type Error = ParseMunicipioError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..6]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[6..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 6) {
+            return Err(ParseMunicipioError::InvalidNumber);
+        }
+
+        Ok([LUHN.check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for Municipio {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Municipio> for [u8; 7] {
+    #[inline]
+    fn from(municipio: Municipio) -> [u8; 7] {
+        municipio.0
+    }
+}
+
+impl From<Municipio> for u32 {
+    #[inline]
+    fn from(municipio: Municipio) -> u32 {
+        municipio.code()
+    }
+}
+
+impl TryFrom<&[u8]> for Municipio {
+    type Error = ParseMunicipioError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 7]> for Municipio {
+    type Error = ParseMunicipioError;
+
+    #[inline]
+    fn try_from(value: &[u8; 7]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<u32> for Municipio {
+    type Error = ParseMunicipioError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        let municipio = Self::from_code(value);
+        if value > 9_999_999 || !is_valid(&municipio.0) {
+            return Err(ParseMunicipioError::InvalidNumber);
+        }
+
+        Ok(municipio)
+    }
+}
+
+impl fmt::Debug for Municipio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Municipio(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Municipio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for number in &self.0 {
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Municipio {
+    type Err = ParseMunicipioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 7];
+
+        if s.is_empty() {
+            return Err(ParseMunicipioError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match ch {
+                '0'..='9' => {
+                    if i < 7 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseMunicipioError::InvalidNumber);
+                    }
+                }
+                _ => return Err(ParseMunicipioError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 7 || !is_valid(&numbers) {
+            return Err(ParseMunicipioError::InvalidNumber);
+        }
+
+        Ok(Municipio(numbers))
+    }
+}
+
+fn is_valid(numbers: &[u8; 7]) -> bool {
+    if Uf::from_ibge_code(numbers[0] * 10 + numbers[1]).is_none() {
+        return false;
+    }
+
+    LUHN.is_valid(numbers) || EXCEPTIONS.contains(&Municipio(*numbers).code())
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Municipio> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Municipio {
+        let uf = Uf::ALL[rng.random_range(0..Uf::ALL.len())];
+
+        let mut numbers = [0; 7];
+        numbers[0] = uf.ibge_code() / 10;
+        numbers[1] = uf.ibge_code() % 10;
+        for number in &mut numbers[2..6] {
+            *number = rng.random_range(0..=9);
+        }
+        numbers[6] = LUHN.check_digit(&numbers[..6]); // check digit
+
+        Municipio(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Municipio {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Municipio {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Municipio([3, 5, 5, 0, 3, 0, 8]);
+        let b: [u8; 7] = [3, 5, 5, 0, 3, 0, 8];
+        let c: [u8; 6] = [3, 5, 5, 0, 3, 0];
+
+        assert_eq!(a, Municipio::from_slice(&b).unwrap());
+        assert_eq!(a, Municipio::from_slice(&c).unwrap());
+        assert_eq!(
+            Municipio::from_slice(&[3, 5, 5, 0, 3, 0, 9]),
+            Err(ParseMunicipioError::InvalidNumber)
+        );
+        assert_eq!(
+            Municipio::from_slice(&[3, 4, 5, 0, 3, 0]),
+            Err(ParseMunicipioError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn code() {
+        let a = Municipio([5, 3, 0, 0, 1, 0, 8]);
+
+        assert_eq!(5300108, a.code());
+        assert_eq!(Uf::Df, a.uf());
+        assert_eq!(Ok(a), Municipio::try_from(5300108));
+        assert_eq!(
+            Err(ParseMunicipioError::InvalidNumber),
+            Municipio::try_from(53001080)
+        );
+    }
+
+    #[test]
+    fn exceptions() {
+        for code in EXCEPTIONS {
+            assert!(Municipio::try_from(code).is_ok());
+        }
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Municipio::generate();
+        let b = a.to_string().parse::<Municipio>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn document() {
+        let a = Municipio([3, 3, 0, 4, 5, 5, 7]);
+
+        assert_eq!(&[3, 3, 0, 4, 5, 5], a.base());
+        assert_eq!(&[7], a.check_digits());
+        assert_eq!(
+            Ok([7]),
+            Municipio::compute_check_digits(&[3, 3, 0, 4, 5, 5])
+        );
+        assert_eq!(Municipio::FORMATTED_LEN, format!("{a}").len());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Municipio("3550308")"#;
+        let b = Municipio([3, 5, 5, 0, 3, 0, 8]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "3550308".parse::<Municipio>().unwrap();
+
+        assert_eq!(a, Municipio([3, 5, 5, 0, 3, 0, 8]));
+        assert_eq!("".parse::<Municipio>(), Err(ParseMunicipioError::Empty));
+        assert_eq!(
+            "35.50308".parse::<Municipio>(),
+            Err(ParseMunicipioError::InvalidCharacter('.', 2))
+        );
+        assert_eq!(
+            "3550309".parse::<Municipio>(),
+            Err(ParseMunicipioError::InvalidNumber)
+        );
+        assert_eq!(
+            "0000000".parse::<Municipio>(),
+            Err(ParseMunicipioError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let municipio_str = "3550308";
+        let municipio = Municipio::from_str(municipio_str).unwrap();
+        serde_test::assert_tokens(&municipio, &[serde_test::Token::Str(municipio_str)]);
+    }
+}
@@ -0,0 +1,413 @@
+// nire.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+use crate::uf::Uf;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// Applied from right to left
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+/// An error which can be returned when parsing a [`Nire`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseNireError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseNireError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseNireError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid NIRE number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseNireError {}
+
+/// A valid NIRE (_Número de Identificação do Registro de Empresas_) number, issued by the
+/// commercial registry (_junta comercial_) of a state. It consists of the IBGE code of the state,
+/// the class of legal nature of the company, a sequence and a check digit. Parsing recognizes
+/// numbers with or without separators (dot and minus).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nire([u8; 11]);
+
+impl Nire {
+    /// Parses a byte slice of numbers as a NIRE, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Nire;
+    ///
+    /// match Nire::from_slice(&[3, 5, 2, 1, 2, 3, 4, 5, 6, 7, 5]) {
+    ///     Ok(nire) => println!("{nire} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::Nire;
+    ///
+    /// match Nire::from_slice(&[3, 5, 2, 1, 2, 3, 4, 5, 6, 7]) {
+    ///     Ok(nire) => println!("{nire} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseNireError> {
+        let mut numbers = [0; 11];
+        match slice.len() {
+            0 => return Err(ParseNireError::Empty),
+            len @ (10 | 11) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseNireError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseNireError::InvalidNumber);
+        }
+
+        // Checks the state
+        if Uf::from_ibge_code(numbers[0] * 10 + numbers[1]).is_none() {
+            return Err(ParseNireError::InvalidNumber);
+        }
+
+        let check_digit = CHECKSUM.check_digit(&numbers[..10]);
+        if slice.len() == 10 {
+            numbers[10] = check_digit;
+        } else if check_digit != numbers[10] {
+            return Err(ParseNireError::InvalidNumber);
+        }
+
+        Ok(Nire(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Nire;
+    ///
+    /// let nire = "35.2.1234567-5".parse::<Nire>().expect("invalid NIRE");
+    /// let digits = nire.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 11] {
+        &self.0
+    }
+
+    /// Returns the state of the commercial registry.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Nire, Uf};
+    ///
+    /// let nire = "35.2.1234567-5".parse::<Nire>().expect("invalid NIRE");
+    /// assert_eq!(Uf::Sp, nire.uf());
+    /// ```
+    pub fn uf(&self) -> Uf {
+        match Uf::from_ibge_code(self.0[0] * 10 + self.0[1]) {
+            Some(uf) => uf,
+            None => unreachable!("state already checked"),
+        }
+    }
+
+    /// Returns the class of legal nature of the company, such as `1` for an _empresário_
+    /// (sole proprietor), `2` for a _sociedade limitada_ and `3` for a _sociedade anônima_.
+    #[inline]
+    pub fn class(&self) -> u8 {
+        self.0[2]
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Nire;
+    ///
+    /// let nire = Nire::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Nire {
+    const NAME: &'static str = "NIRE";
+    const LEN: usize = 11;
+    const FORMATTED_LEN: usize = 14;
+
+    type Error = ParseNireError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..10]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[10..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 10) {
+            return Err(ParseNireError::InvalidNumber);
+        }
+
+        Ok([CHECKSUM.check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for Nire {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Nire> for [u8; 11] {
+    #[inline]
+    fn from(nire: Nire) -> [u8; 11] {
+        nire.0
+    }
+}
+
+impl TryFrom<&[u8]> for Nire {
+    type Error = ParseNireError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 11]> for Nire {
+    type Error = ParseNireError;
+
+    #[inline]
+    fn try_from(value: &[u8; 11]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Nire {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Nire(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Nire {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                2 | 3 => f.write_char('.')?,
+                10 => f.write_char('-')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Nire {
+    type Err = ParseNireError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 11];
+
+        if s.is_empty() {
+            return Err(ParseNireError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 11 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseNireError::InvalidNumber);
+                    }
+                }
+                ('.', 2 | 4) | ('-', 12) => continue,
+                _ => return Err(ParseNireError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 11 {
+            return Err(ParseNireError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Nire> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nire {
+        let mut numbers = [0; 11];
+        let uf = Uf::ALL[rng.random_range(0..Uf::ALL.len())].ibge_code();
+        numbers[..2].copy_from_slice(&[uf / 10, uf % 10]);
+        numbers[2] = rng.random_range(1..=9);
+        for number in &mut numbers[3..10] {
+            *number = rng.random_range(0..=9);
+        }
+
+        numbers[10] = CHECKSUM.check_digit(&numbers[..10]); // check digit
+
+        Nire(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Nire {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Nire {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Nire([3, 5, 2, 1, 2, 3, 4, 5, 6, 7, 5]);
+
+        assert_eq!(Ok(a), Nire::from_slice(&a.0));
+        assert_eq!(Ok(a), Nire::from_slice(&a.0[..10]));
+        assert!(Nire::from_slice(&[3, 5, 3, 0, 0, 1, 2, 3, 4, 5, 3]).is_ok());
+        assert_eq!(
+            Nire::from_slice(&[3, 5, 2, 1, 2, 3, 4, 5, 6, 7, 6]),
+            Err(ParseNireError::InvalidNumber)
+        );
+        assert_eq!(
+            Nire::from_slice(&[3, 0, 2, 1, 2, 3, 4, 5, 6, 7]),
+            Err(ParseNireError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let a = Nire([3, 5, 2, 1, 2, 3, 4, 5, 6, 7, 5]);
+        let b = Nire([5, 3, 1, 0, 0, 0, 0, 0, 0, 3, 8]);
+
+        assert_eq!(Uf::Sp, a.uf());
+        assert_eq!(2, a.class());
+        assert_eq!(Uf::Df, b.uf());
+        assert_eq!(1, b.class());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Nire::generate();
+        let b = a.to_string().parse::<Nire>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Nire("35.2.1234567-5")"#;
+        let b = Nire([3, 5, 2, 1, 2, 3, 4, 5, 6, 7, 5]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "35.2.1234567-5".parse::<Nire>().unwrap();
+        let b = "35212345675".parse::<Nire>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!("".parse::<Nire>(), Err(ParseNireError::Empty));
+        assert_eq!(
+            "35-2.1234567-5".parse::<Nire>(),
+            Err(ParseNireError::InvalidCharacter('-', 2))
+        );
+        assert_eq!(
+            "35.2.1234567-6".parse::<Nire>(),
+            Err(ParseNireError::InvalidNumber)
+        );
+        assert_eq!(
+            "35.2.1234567".parse::<Nire>(),
+            Err(ParseNireError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let nire_str = "35.2.1234567-5";
+        let nire = Nire::from_str(nire_str).unwrap();
+        serde_test::assert_tokens(&nire, &[serde_test::Token::Str(nire_str)]);
+    }
+}
@@ -0,0 +1,426 @@
+// nis.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 3, 2, 9, 8, 7, 6, 5, 4, 3, 2, applied from right to left
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+/// An error which can be returned when parsing an [`Nis`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseNisError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseNisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseNisError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid NIS number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseNisError {}
+
+/// A valid NIS number, also known as PIS, PASEP and NIT. Parsing recognizes numbers with or
+/// without separators (dot and minus).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Nis([u8; 11]);
+
+impl Nis {
+    /// Parses a byte slice of numbers as an NIS, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Nis;
+    ///
+    /// match Nis::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]) {
+    ///     Ok(nis) => println!("{nis} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::Nis;
+    ///
+    /// match Nis::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0]) {
+    ///     Ok(nis) => println!("{nis} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseNisError> {
+        let mut numbers = [0; 11];
+        match slice.len() {
+            0 => return Err(ParseNisError::Empty),
+            len @ (10 | 11) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseNisError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if slice.len() == 11 && numbers.iter().all(|&x| x == first_number) {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        let expected = CHECKSUM.check_digit(&numbers[..10]);
+        if slice.len() < 11 {
+            numbers[10] = expected; // check digit
+        } else if expected != numbers[10] {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        Ok(Nis(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Nis;
+    ///
+    /// let nis = "123.45678.90-0".parse::<Nis>().expect("invalid NIS");
+    /// let digits = nis.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 11] {
+        &self.0
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Nis;
+    ///
+    /// let nis = Nis::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Nis {
+    const NAME: &'static str = "NIS";
+    const LEN: usize = 11;
+    const FORMATTED_LEN: usize = 14;
+
+    type Error = ParseNisError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..10]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[10..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 10) {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        Ok([CHECKSUM.check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for Nis {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Nis> for [u8; 11] {
+    #[inline]
+    fn from(nis: Nis) -> [u8; 11] {
+        nis.0
+    }
+}
+
+impl TryFrom<&[u8]> for Nis {
+    type Error = ParseNisError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 11]> for Nis {
+    type Error = ParseNisError;
+
+    #[inline]
+    fn try_from(value: &[u8; 11]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Nis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Nis(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Nis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                3 | 8 => f.write_char('.')?,
+                10 => f.write_char('-')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Nis {
+    type Err = ParseNisError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 11];
+
+        if s.is_empty() {
+            return Err(ParseNisError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        let mut has_dot = false;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 11 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseNisError::InvalidNumber);
+                    }
+                }
+                ('.', 3 | 9) => has_dot = true,
+                ('-', 12) if has_dot => continue,
+                ('-', 10) if !has_dot => continue,
+                _ => return Err(ParseNisError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 11 {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers.iter().all(|&x| x == first_number) {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        if CHECKSUM.check_digit(&numbers[..10]) != numbers[10] {
+            return Err(ParseNisError::InvalidNumber);
+        }
+
+        Ok(Nis(numbers))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Nis> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Nis {
+        let mut numbers = [0; 11];
+        for number in &mut numbers[..10] {
+            *number = rng.random_range(0..=9);
+        }
+        numbers[10] = CHECKSUM.check_digit(&numbers[..10]); // check digit
+
+        Nis(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Nis {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Nis {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+    use crate::DisplayStyle;
+
+    #[test]
+    fn from_slice() {
+        let a = Nis([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+        let b: [u8; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0];
+        let c: [u8; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0];
+
+        assert_eq!(a, Nis::from_slice(&b).unwrap());
+        assert_eq!(a, Nis::from_slice(&c).unwrap());
+        assert_eq!(
+            Nis::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 1]),
+            Err(ParseNisError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn as_bytes() {
+        let a: [u8; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0];
+        let b = Nis([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+
+        assert_eq!(&a, b.as_bytes());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Nis::generate();
+        let b = a.to_string().parse::<Nis>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn document() {
+        let a = Nis([1, 7, 0, 3, 3, 2, 5, 9, 5, 0, 4]);
+
+        assert_eq!(&[1, 7, 0, 3, 3, 2, 5, 9, 5, 0], a.base());
+        assert_eq!(&[4], a.check_digits());
+        assert_eq!(
+            Ok([4]),
+            Nis::compute_check_digits(&[1, 7, 0, 3, 3, 2, 5, 9, 5, 0])
+        );
+        assert_eq!(Nis::FORMATTED_LEN, format!("{a}").len());
+        assert_eq!(
+            "17033259504",
+            format!("{}", a.display_style(DisplayStyle::Digits))
+        );
+    }
+
+    #[test]
+    fn try_from() {
+        let a: [u8; 11] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0];
+        let b = Nis([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+
+        assert_eq!(Nis::try_from(&a).unwrap(), b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Nis("123.45678.90-0")"#;
+        let b = Nis([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = "123.45678.90-0";
+        let b = Nis([1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+
+        assert_eq!(a, format!("{b}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "123.45678.90-0".parse::<Nis>().unwrap();
+        let b = "1234567890-0".parse::<Nis>().unwrap();
+        let c = "12345678900".parse::<Nis>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert_eq!("".parse::<Nis>(), Err(ParseNisError::Empty));
+        assert_eq!(
+            "123-45678-90-0".parse::<Nis>(),
+            Err(ParseNisError::InvalidCharacter('-', 3))
+        );
+        assert_eq!(
+            "123.45678.90-1".parse::<Nis>(),
+            Err(ParseNisError::InvalidNumber)
+        );
+        assert_eq!(
+            "000.00000.00-0".parse::<Nis>(),
+            Err(ParseNisError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let nis_str = "123.45678.90-0";
+        let nis = Nis::from_str(nis_str).unwrap();
+        serde_test::assert_tokens(&nis, &[serde_test::Token::Str(nis_str)]);
+    }
+}
@@ -0,0 +1,382 @@
+// numero_beneficio.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// Applied from right to left
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+/// An error which can be returned when parsing a [`NumeroBeneficio`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseNumeroBeneficioError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseNumeroBeneficioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseNumeroBeneficioError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid benefit number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseNumeroBeneficioError {}
+
+/// A valid INSS benefit number (_número de benefício_, NB). Parsing recognizes numbers with or
+/// without separators (dot and minus).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NumeroBeneficio([u8; 10]);
+
+impl NumeroBeneficio {
+    /// Parses a byte slice of numbers as a benefit number, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::NumeroBeneficio;
+    ///
+    /// match NumeroBeneficio::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 7]) {
+    ///     Ok(nb) => println!("{nb} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::NumeroBeneficio;
+    ///
+    /// match NumeroBeneficio::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9]) {
+    ///     Ok(nb) => println!("{nb} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseNumeroBeneficioError> {
+        let mut numbers = [0; 10];
+        match slice.len() {
+            0 => return Err(ParseNumeroBeneficioError::Empty),
+            len @ (9 | 10) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseNumeroBeneficioError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseNumeroBeneficioError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers[..9].iter().all(|&x| x == first_number) {
+            return Err(ParseNumeroBeneficioError::InvalidNumber);
+        }
+
+        let check_digit = CHECKSUM.check_digit(&numbers[..9]);
+        if slice.len() == 9 {
+            numbers[9] = check_digit;
+        } else if check_digit != numbers[9] {
+            return Err(ParseNumeroBeneficioError::InvalidNumber);
+        }
+
+        Ok(NumeroBeneficio(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::NumeroBeneficio;
+    ///
+    /// let nb = "123.456.789-7".parse::<NumeroBeneficio>().expect("invalid NB");
+    /// let digits = nb.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 10] {
+        &self.0
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::NumeroBeneficio;
+    ///
+    /// let nb = NumeroBeneficio::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for NumeroBeneficio {
+    const NAME: &'static str = "NB";
+    const LEN: usize = 10;
+    const FORMATTED_LEN: usize = 13;
+
+    type Error = ParseNumeroBeneficioError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..9]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[9..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 9) {
+            return Err(ParseNumeroBeneficioError::InvalidNumber);
+        }
+
+        Ok([CHECKSUM.check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for NumeroBeneficio {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<NumeroBeneficio> for [u8; 10] {
+    #[inline]
+    fn from(nb: NumeroBeneficio) -> [u8; 10] {
+        nb.0
+    }
+}
+
+impl TryFrom<&[u8]> for NumeroBeneficio {
+    type Error = ParseNumeroBeneficioError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 10]> for NumeroBeneficio {
+    type Error = ParseNumeroBeneficioError;
+
+    #[inline]
+    fn try_from(value: &[u8; 10]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for NumeroBeneficio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "NumeroBeneficio(\"{self}\")")
+    }
+}
+
+impl fmt::Display for NumeroBeneficio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                3 | 6 => f.write_char('.')?,
+                9 => f.write_char('-')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NumeroBeneficio {
+    type Err = ParseNumeroBeneficioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 10];
+
+        if s.is_empty() {
+            return Err(ParseNumeroBeneficioError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 10 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseNumeroBeneficioError::InvalidNumber);
+                    }
+                }
+                ('.', 3 | 7) | ('-', 11) => continue,
+                _ => return Err(ParseNumeroBeneficioError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 10 {
+            return Err(ParseNumeroBeneficioError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<NumeroBeneficio> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> NumeroBeneficio {
+        let mut numbers = [0; 10];
+        for number in &mut numbers[..9] {
+            *number = rng.random_range(0..=9);
+        }
+
+        // Repeated numbers
+        let first_number = numbers[0];
+        if numbers[..9].iter().all(|&x| x == first_number) {
+            return self.sample(rng);
+        }
+
+        numbers[9] = CHECKSUM.check_digit(&numbers[..9]); // check digit
+
+        NumeroBeneficio(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for NumeroBeneficio {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for NumeroBeneficio {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = NumeroBeneficio([1, 2, 3, 4, 5, 6, 7, 8, 9, 7]);
+
+        assert_eq!(Ok(a), NumeroBeneficio::from_slice(&a.0));
+        assert_eq!(Ok(a), NumeroBeneficio::from_slice(&a.0[..9]));
+        assert!(NumeroBeneficio::from_slice(&[6, 0, 0, 1, 2, 3, 4, 5, 6, 0]).is_ok());
+        assert_eq!(
+            NumeroBeneficio::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 8]),
+            Err(ParseNumeroBeneficioError::InvalidNumber)
+        );
+        assert_eq!(
+            NumeroBeneficio::from_slice(&[1, 1, 1, 1, 1, 1, 1, 1, 1, 1]),
+            Err(ParseNumeroBeneficioError::InvalidNumber)
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = NumeroBeneficio::generate();
+        let b = a.to_string().parse::<NumeroBeneficio>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"NumeroBeneficio("123.456.789-7")"#;
+        let b = NumeroBeneficio([1, 2, 3, 4, 5, 6, 7, 8, 9, 7]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "123.456.789-7".parse::<NumeroBeneficio>().unwrap();
+        let b = "1234567897".parse::<NumeroBeneficio>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(
+            "".parse::<NumeroBeneficio>(),
+            Err(ParseNumeroBeneficioError::Empty)
+        );
+        assert_eq!(
+            "123.456.789/7".parse::<NumeroBeneficio>(),
+            Err(ParseNumeroBeneficioError::InvalidCharacter('/', 11))
+        );
+        assert_eq!(
+            "123.456.789-8".parse::<NumeroBeneficio>(),
+            Err(ParseNumeroBeneficioError::InvalidNumber)
+        );
+        assert_eq!(
+            "123.456.789".parse::<NumeroBeneficio>(),
+            Err(ParseNumeroBeneficioError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let nb_str = "123.456.789-7";
+        let nb = NumeroBeneficio::from_str(nb_str).unwrap();
+        serde_test::assert_tokens(&nb, &[serde_test::Token::Str(nb_str)]);
+    }
+}
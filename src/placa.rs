@@ -0,0 +1,346 @@
+// placa.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+/// An error which can be returned when parsing a [`Placa`].
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParsePlacaError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParsePlacaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParsePlacaError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid license plate"),
+        }
+    }
+}
+
+impl core::error::Error for ParsePlacaError {}
+
+/// A vehicle license plate, in the old (`ABC-1234`) or the Mercosul (`ABC1D23`) format.
+/// Parsing recognizes plates in upper or lower case, with or without separators (minus and
+/// space).
+///
+/// In the Mercosul format, the second digit of the old format is replaced by a letter, from `A`
+/// (0) to `J` (9), so a vehicle keeps its plate when converted; see
+/// [`Placa::is_same_vehicle`].
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Placa([u8; 7]);
+
+impl Placa {
+    /// Returns the plate as a string, without separators.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Placa;
+    ///
+    /// let placa = "abc-1234".parse::<Placa>().expect("invalid plate");
+    /// assert_eq!("ABC1234", placa.as_str());
+    /// ```
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // SAFETY: Only ASCII letters and digits are stored
+        unsafe { core::str::from_utf8_unchecked(&self.0) }
+    }
+
+    /// Returns `true` if the plate is in the Mercosul format.
+    #[inline]
+    pub fn is_mercosul(&self) -> bool {
+        self.0[4].is_ascii_uppercase()
+    }
+
+    /// Converts the plate to the Mercosul format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Placa;
+    ///
+    /// let placa = "ABC-1234".parse::<Placa>().expect("invalid plate");
+    /// assert_eq!("ABC1C34", placa.to_mercosul().to_string());
+    /// ```
+    pub fn to_mercosul(&self) -> Self {
+        let mut placa = *self;
+        if !self.is_mercosul() {
+            placa.0[4] = self.0[4] - b'0' + b'A';
+        }
+        placa
+    }
+
+    /// Converts the plate to the old format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Placa;
+    ///
+    /// let placa = "ABC1C34".parse::<Placa>().expect("invalid plate");
+    /// assert_eq!("ABC-1234", placa.to_old().to_string());
+    /// ```
+    pub fn to_old(&self) -> Self {
+        let mut placa = *self;
+        if self.is_mercosul() {
+            placa.0[4] = self.0[4] - b'A' + b'0';
+        }
+        placa
+    }
+
+    /// Returns `true` if both plates belong to the same vehicle, regardless of the format.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Placa;
+    ///
+    /// let a = "ABC-1234".parse::<Placa>().expect("invalid plate");
+    /// let b = "ABC1C34".parse::<Placa>().expect("invalid plate");
+    /// assert!(a.is_same_vehicle(&b));
+    /// ```
+    #[inline]
+    pub fn is_same_vehicle(&self, other: &Self) -> bool {
+        self.to_mercosul() == other.to_mercosul()
+    }
+
+    /// Generates a random plate, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Placa;
+    ///
+    /// let placa = Placa::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl AsRef<str> for Placa {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for Placa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Placa(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Placa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (letters, rest) = self.as_str().split_at(3);
+        f.write_str(letters)?;
+        if !self.is_mercosul() {
+            f.write_char('-')?;
+        }
+        f.write_str(rest)
+    }
+}
+
+impl FromStr for Placa {
+    type Err = ParsePlacaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = [0; 7];
+
+        if s.is_empty() {
+            return Err(ParsePlacaError::Empty);
+        }
+
+        // Checks for invalid symbols and converts letters to upper case
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            let ch_upper = ch.to_ascii_uppercase();
+            match (ch_upper, i) {
+                ('-' | ' ', 3) if offset == 3 => continue,
+                (_, 7..) => return Err(ParsePlacaError::InvalidNumber),
+                ('A'..='Z', 0..=2) | ('0'..='9', 3 | 5 | 6) | ('0'..='9' | 'A'..='J', 4) => {
+                    chars[i] = ch_upper as u8;
+                    i += 1;
+                }
+                _ => return Err(ParsePlacaError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 7 {
+            return Err(ParsePlacaError::InvalidNumber);
+        }
+
+        Ok(Placa(chars))
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Placa> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Placa {
+        let mut chars = [0; 7];
+        for ch in &mut chars[..3] {
+            *ch = rng.random_range(b'A'..=b'Z');
+        }
+        for ch in &mut chars[3..] {
+            *ch = rng.random_range(b'0'..=b'9');
+        }
+
+        let placa = Placa(chars);
+        if rng.random() {
+            placa.to_mercosul()
+        } else {
+            placa
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Placa {
+    /// Serializes the plate without separators, as returned by [`Placa::as_str`].
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Placa {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PlacaStringVisitor;
+
+        impl de::Visitor<'_> for PlacaStringVisitor {
+            type Value = Placa;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a license plate string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Placa, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(PlacaStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn convert() {
+        let a = Placa(*b"ABC1234");
+        let b = Placa(*b"ABC1C34");
+
+        assert!(!a.is_mercosul());
+        assert!(b.is_mercosul());
+        assert_eq!(b, a.to_mercosul());
+        assert_eq!(a, b.to_old());
+        assert_eq!(a, a.to_old());
+        assert_eq!(b, b.to_mercosul());
+        assert!(a.is_same_vehicle(&b));
+        assert!(!a.is_same_vehicle(&Placa(*b"ABC1D34")));
+        assert_eq!(Placa(*b"XYZ9J99"), Placa(*b"XYZ9999").to_mercosul());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Placa::generate();
+        let b = a.to_string().parse::<Placa>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Placa("ABC-1234")"#;
+        let b = Placa(*b"ABC1234");
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("ABC-1234", format!("{}", Placa(*b"ABC1234")));
+        assert_eq!("ABC1C34", format!("{}", Placa(*b"ABC1C34")));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = Placa(*b"ABC1234");
+        let b = Placa(*b"ABC1C34");
+
+        assert_eq!(Ok(a), "ABC-1234".parse());
+        assert_eq!(Ok(a), "abc1234".parse());
+        assert_eq!(Ok(a), "Abc 1234".parse());
+        assert_eq!(Ok(b), "ABC1C34".parse());
+        assert_eq!(Ok(b), "abc-1c34".parse());
+        assert_eq!("".parse::<Placa>(), Err(ParsePlacaError::Empty));
+        assert_eq!(
+            "AB-C1234".parse::<Placa>(),
+            Err(ParsePlacaError::InvalidCharacter('-', 2))
+        );
+        assert_eq!(
+            "ABC1K34".parse::<Placa>(),
+            Err(ParsePlacaError::InvalidCharacter('K', 4))
+        );
+        assert_eq!(
+            "1BC1234".parse::<Placa>(),
+            Err(ParsePlacaError::InvalidCharacter('1', 0))
+        );
+        assert_eq!(
+            "ABC123".parse::<Placa>(),
+            Err(ParsePlacaError::InvalidNumber)
+        );
+        assert_eq!(
+            "ABC12345".parse::<Placa>(),
+            Err(ParsePlacaError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let placa_str = "ABC1234";
+        let placa = Placa::from_str(placa_str).unwrap();
+        serde_test::assert_tokens(&placa, &[serde_test::Token::Str(placa_str)]);
+        serde_test::assert_de_tokens(&placa, &[serde_test::Token::Str("ABC-1234")]);
+    }
+}
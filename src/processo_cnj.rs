@@ -0,0 +1,502 @@
+// processo_cnj.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::mod97;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+/// An error which can be returned when parsing a [`ProcessoCnj`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseProcessoCnjError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseProcessoCnjError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseProcessoCnjError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid CNJ process number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseProcessoCnjError {}
+
+/// The segment of the Judiciary (J) of a [`ProcessoCnj`] number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Segment {
+    /// Supremo Tribunal Federal
+    Stf = 1,
+    /// Conselho Nacional de Justiça
+    Cnj,
+    /// Superior Tribunal de Justiça
+    Stj,
+    /// Justiça Federal
+    Federal,
+    /// Justiça do Trabalho
+    Trabalho,
+    /// Justiça Eleitoral
+    Eleitoral,
+    /// Justiça Militar da União
+    MilitarUniao,
+    /// Justiça dos Estados e do Distrito Federal
+    Estadual,
+    /// Justiça Militar Estadual
+    MilitarEstadual,
+}
+
+impl Segment {
+    /// Returns the segment with the code, if there is one.
+    pub const fn from_code(code: u8) -> Option<Self> {
+        use Segment::*;
+        match code {
+            1 => Some(Stf),
+            2 => Some(Cnj),
+            3 => Some(Stj),
+            4 => Some(Federal),
+            5 => Some(Trabalho),
+            6 => Some(Eleitoral),
+            7 => Some(MilitarUniao),
+            8 => Some(Estadual),
+            9 => Some(MilitarEstadual),
+            _ => None,
+        }
+    }
+
+    /// Returns the code, as in the J field.
+    #[inline]
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+}
+
+/// A valid CNJ unified process number (`NNNNNNN-DD.AAAA.J.TR.OOOO`). Parsing recognizes numbers
+/// with or without separators (minus and dot).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ProcessoCnj([u8; 20]);
+
+impl ProcessoCnj {
+    /// Parses a byte slice of numbers as a CNJ process number, guessing the missing parts. Slices
+    /// of 18 numbers are taken as the number without the check digits (`NNNNNNNAAAAJTROOOO`).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::ProcessoCnj;
+    ///
+    /// let numbers = [0, 0, 0, 1, 2, 3, 4, 0, 8, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0];
+    /// match ProcessoCnj::from_slice(&numbers) {
+    ///     Ok(processo) => println!("{processo} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digits:
+    ///
+    /// ```rust
+    /// use brids::ProcessoCnj;
+    ///
+    /// let numbers = [0, 0, 0, 1, 2, 3, 4, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0];
+    /// match ProcessoCnj::from_slice(&numbers) {
+    ///     Ok(processo) => println!("{processo} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseProcessoCnjError> {
+        let mut numbers = [0; 20];
+        match slice.len() {
+            0 => return Err(ParseProcessoCnjError::Empty),
+            18 => {
+                numbers[..7].copy_from_slice(&slice[..7]);
+                numbers[9..].copy_from_slice(&slice[7..]);
+            }
+            20 => numbers.copy_from_slice(slice),
+            _ => return Err(ParseProcessoCnjError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseProcessoCnjError::InvalidNumber);
+        }
+
+        if slice.len() == 18 {
+            let check_digits = calc_check_digits(&numbers);
+            numbers[7] = check_digits / 10;
+            numbers[8] = check_digits % 10;
+        }
+
+        if !is_valid(&numbers) {
+            return Err(ParseProcessoCnjError::InvalidNumber);
+        }
+
+        Ok(ProcessoCnj(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::ProcessoCnj;
+    ///
+    /// let processo = "0001234-08.2023.8.26.0100".parse::<ProcessoCnj>().expect("invalid number");
+    /// let digits = processo.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 20] {
+        &self.0
+    }
+
+    /// Returns the sequential number of the process in the originating unit (NNNNNNN).
+    #[inline]
+    pub fn sequence(&self) -> u32 {
+        to_u32(&self.0[..7])
+    }
+
+    /// Returns the year the process was filed (AAAA).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::ProcessoCnj;
+    ///
+    /// let processo = "0001234-08.2023.8.26.0100".parse::<ProcessoCnj>().expect("invalid number");
+    /// assert_eq!(2023, processo.year());
+    /// ```
+    #[inline]
+    pub fn year(&self) -> u16 {
+        to_u32(&self.0[9..13]) as u16
+    }
+
+    /// Returns the segment of the Judiciary (J).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{ProcessoCnj, Segment};
+    ///
+    /// let processo = "0001234-08.2023.8.26.0100".parse::<ProcessoCnj>().expect("invalid number");
+    /// assert_eq!(Segment::Estadual, processo.segment());
+    /// ```
+    pub fn segment(&self) -> Segment {
+        match Segment::from_code(self.0[13]) {
+            Some(segment) => segment,
+            None => unreachable!("segment already checked"),
+        }
+    }
+
+    /// Returns the court (TR), as in the number of the state court when the segment is
+    /// [`Segment::Estadual`].
+    #[inline]
+    pub fn court(&self) -> u8 {
+        self.0[14] * 10 + self.0[15]
+    }
+
+    /// Returns the originating unit (OOOO).
+    #[inline]
+    pub fn origin(&self) -> u16 {
+        to_u32(&self.0[16..]) as u16
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::ProcessoCnj;
+    ///
+    /// let processo = ProcessoCnj::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl AsRef<[u8]> for ProcessoCnj {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<ProcessoCnj> for [u8; 20] {
+    #[inline]
+    fn from(processo: ProcessoCnj) -> [u8; 20] {
+        processo.0
+    }
+}
+
+impl TryFrom<&[u8]> for ProcessoCnj {
+    type Error = ParseProcessoCnjError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 20]> for ProcessoCnj {
+    type Error = ParseProcessoCnjError;
+
+    #[inline]
+    fn try_from(value: &[u8; 20]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for ProcessoCnj {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ProcessoCnj(\"{self}\")")
+    }
+}
+
+impl fmt::Display for ProcessoCnj {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                7 => f.write_char('-')?,
+                9 | 13 | 14 | 16 => f.write_char('.')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ProcessoCnj {
+    type Err = ParseProcessoCnjError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 20];
+
+        if s.is_empty() {
+            return Err(ParseProcessoCnjError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 20 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseProcessoCnjError::InvalidNumber);
+                    }
+                }
+                ('-', 7) => continue,
+                ('.', 10 | 15 | 17 | 20) => continue,
+                _ => return Err(ParseProcessoCnjError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 20 || !is_valid(&numbers) {
+            return Err(ParseProcessoCnjError::InvalidNumber);
+        }
+
+        Ok(ProcessoCnj(numbers))
+    }
+}
+
+fn to_u32(numbers: &[u8]) -> u32 {
+    numbers.iter().fold(0, |acc, &x| acc * 10 + u32::from(x))
+}
+
+/// Computes the check digits (DD), over the other numbers in the `NNNNNNNAAAAJTROOOO` order.
+fn calc_check_digits(numbers: &[u8; 20]) -> u8 {
+    let mut base = [0; 18];
+    base[..7].copy_from_slice(&numbers[..7]);
+    base[7..].copy_from_slice(&numbers[9..]);
+    mod97(&base)
+}
+
+fn is_valid(numbers: &[u8; 20]) -> bool {
+    Segment::from_code(numbers[13]).is_some()
+        && calc_check_digits(numbers) == numbers[7] * 10 + numbers[8]
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<ProcessoCnj> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ProcessoCnj {
+        let mut numbers = [0; 20];
+        for number in &mut numbers {
+            *number = rng.random_range(0..=9);
+        }
+
+        // Year and segment
+        let year: u16 = rng.random_range(1990..=2030);
+        for (i, number) in numbers[9..13].iter_mut().rev().enumerate() {
+            *number = (year / 10u16.pow(i as u32) % 10) as u8;
+        }
+        numbers[13] = rng.random_range(1..=9);
+
+        let check_digits = calc_check_digits(&numbers);
+        numbers[7] = check_digits / 10;
+        numbers[8] = check_digits % 10;
+
+        ProcessoCnj(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ProcessoCnj {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(not(feature = "std"))]
+        use crate::alloc::string::ToString;
+
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ProcessoCnj {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ProcessoCnjStringVisitor;
+
+        impl de::Visitor<'_> for ProcessoCnjStringVisitor {
+            type Value = ProcessoCnj;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a CNJ process number string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<ProcessoCnj, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<ProcessoCnj, E> {
+                ProcessoCnj::from_slice(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(ProcessoCnjStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = ProcessoCnj([0, 0, 0, 1, 2, 3, 4, 0, 8, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0]);
+        let b: [u8; 20] = [0, 0, 0, 1, 2, 3, 4, 0, 8, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0];
+        let c: [u8; 18] = [0, 0, 0, 1, 2, 3, 4, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0];
+
+        assert_eq!(a, ProcessoCnj::from_slice(&b).unwrap());
+        assert_eq!(a, ProcessoCnj::from_slice(&c).unwrap());
+        assert_eq!(
+            ProcessoCnj::from_slice(&[0, 0, 0, 1, 2, 3, 4, 0, 9, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0]),
+            Err(ParseProcessoCnjError::InvalidNumber)
+        );
+        assert_eq!(
+            ProcessoCnj::from_slice(&[0, 0, 0, 1, 2, 3, 4, 2, 0, 2, 3, 0, 2, 6, 0, 1, 0, 0]),
+            Err(ParseProcessoCnjError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn fields() {
+        let a = ProcessoCnj::from_str("0000001-20.2000.4.01.0000").unwrap();
+
+        assert_eq!(1, a.sequence());
+        assert_eq!(2000, a.year());
+        assert_eq!(Segment::Federal, a.segment());
+        assert_eq!(4, a.segment().code());
+        assert_eq!(1, a.court());
+        assert_eq!(0, a.origin());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = ProcessoCnj::generate();
+        let b = a.to_string().parse::<ProcessoCnj>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"ProcessoCnj("0001234-08.2023.8.26.0100")"#;
+        let b = ProcessoCnj([0, 0, 0, 1, 2, 3, 4, 0, 8, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = "0001234-08.2023.8.26.0100";
+        let b = ProcessoCnj([0, 0, 0, 1, 2, 3, 4, 0, 8, 2, 0, 2, 3, 8, 2, 6, 0, 1, 0, 0]);
+
+        assert_eq!(a, format!("{b}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "0001234-08.2023.8.26.0100".parse::<ProcessoCnj>().unwrap();
+        let b = "00012340820238260100".parse::<ProcessoCnj>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!("".parse::<ProcessoCnj>(), Err(ParseProcessoCnjError::Empty));
+        assert_eq!(
+            "0001234.08.2023.8.26.0100".parse::<ProcessoCnj>(),
+            Err(ParseProcessoCnjError::InvalidCharacter('.', 7))
+        );
+        assert_eq!(
+            "0001234-09.2023.8.26.0100".parse::<ProcessoCnj>(),
+            Err(ParseProcessoCnjError::InvalidNumber)
+        );
+        assert_eq!(
+            "0001234-08.2023.8.26.010".parse::<ProcessoCnj>(),
+            Err(ParseProcessoCnjError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let processo_str = "0001234-08.2023.8.26.0100";
+        let processo = ProcessoCnj::from_str(processo_str).unwrap();
+        serde_test::assert_tokens(&processo, &[serde_test::Token::Str(processo_str)]);
+    }
+}
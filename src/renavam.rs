@@ -0,0 +1,423 @@
+// renavam.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 3, 2, 9, 8, 7, 6, 5, 4, 3, 2, applied from right to left
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+/// An error which can be returned when parsing a [`Renavam`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseRenavamError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseRenavamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseRenavamError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid RENAVAM number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseRenavamError {}
+
+/// A valid RENAVAM (vehicle registry) number. Parsing recognizes numbers without separators
+/// only, including the older 9-digit numbers, which are left-padded with zeros.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Renavam([u8; 11]);
+
+impl Renavam {
+    /// Parses a byte slice of numbers as a RENAVAM, guessing the missing parts. Slices of 9
+    /// numbers are older RENAVAM numbers, which are left-padded with zeros.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Renavam;
+    ///
+    /// match Renavam::from_slice(&[6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3]) {
+    ///     Ok(renavam) => println!("{renavam} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::Renavam;
+    ///
+    /// match Renavam::from_slice(&[6, 3, 9, 0, 2, 0, 7, 9, 0, 8]) {
+    ///     Ok(renavam) => println!("{renavam} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Older number:
+    ///
+    /// ```rust
+    /// use brids::Renavam;
+    ///
+    /// match Renavam::from_slice(&[6, 3, 9, 0, 2, 0, 7, 9, 8]) {
+    ///     Ok(renavam) => println!("{renavam} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseRenavamError> {
+        let mut numbers = [0; 11];
+        match slice.len() {
+            0 => return Err(ParseRenavamError::Empty),
+            9 => numbers[2..].copy_from_slice(slice), // `00` + older number
+            len @ (10 | 11) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseRenavamError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseRenavamError::InvalidNumber);
+        }
+
+        let expected = CHECKSUM.check_digit(&numbers[..10]);
+        if slice.len() == 10 {
+            numbers[10] = expected; // check digit
+        } else if expected != numbers[10] {
+            return Err(ParseRenavamError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers.iter().all(|&x| x == first_number) {
+            return Err(ParseRenavamError::InvalidNumber);
+        }
+
+        Ok(Renavam(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Renavam;
+    ///
+    /// let renavam = "63902079083".parse::<Renavam>().expect("invalid RENAVAM");
+    /// let digits = renavam.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 11] {
+        &self.0
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Renavam;
+    ///
+    /// let renavam = Renavam::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Renavam {
+    const NAME: &'static str = "RENAVAM";
+    const LEN: usize = 11;
+    const FORMATTED_LEN: usize = 11;
+
+    type Error = ParseRenavamError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..10]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[10..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 10) {
+            return Err(ParseRenavamError::InvalidNumber);
+        }
+
+        Ok([CHECKSUM.check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for Renavam {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Renavam> for [u8; 11] {
+    #[inline]
+    fn from(renavam: Renavam) -> [u8; 11] {
+        renavam.0
+    }
+}
+
+impl TryFrom<&[u8]> for Renavam {
+    type Error = ParseRenavamError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 11]> for Renavam {
+    type Error = ParseRenavamError;
+
+    #[inline]
+    fn try_from(value: &[u8; 11]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Renavam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Renavam(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Renavam {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for number in &self.0 {
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Renavam {
+    type Err = ParseRenavamError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 11];
+
+        if s.is_empty() {
+            return Err(ParseRenavamError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match ch {
+                '0'..='9' => {
+                    if i < 11 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseRenavamError::InvalidNumber);
+                    }
+                }
+                _ => return Err(ParseRenavamError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 9 && i != 11 {
+            return Err(ParseRenavamError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers[..i])
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Renavam> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Renavam {
+        let mut numbers = [0; 11];
+        for number in &mut numbers[..10] {
+            *number = rng.random_range(0..=9);
+        }
+        numbers[10] = CHECKSUM.check_digit(&numbers[..10]); // check digit
+
+        // Repeated numbers
+        let first_number = numbers[0];
+        if numbers.iter().all(|&x| x == first_number) {
+            return self.sample(rng);
+        }
+
+        Renavam(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Renavam {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Renavam {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Renavam([6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3]);
+        let b: [u8; 11] = [6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3];
+        let c: [u8; 10] = [6, 3, 9, 0, 2, 0, 7, 9, 0, 8];
+        let d: [u8; 9] = [6, 3, 9, 0, 2, 0, 7, 9, 8];
+
+        assert_eq!(a, Renavam::from_slice(&b).unwrap());
+        assert_eq!(a, Renavam::from_slice(&c).unwrap());
+        assert_eq!(
+            Renavam([0, 0, 6, 3, 9, 0, 2, 0, 7, 9, 8]),
+            Renavam::from_slice(&d).unwrap()
+        );
+        assert_eq!(
+            Renavam::from_slice(&[6, 3, 9, 0, 2, 0, 7, 9, 7]),
+            Err(ParseRenavamError::InvalidNumber)
+        );
+
+        // Repeated numbers
+        assert_eq!(
+            Renavam::from_slice(&[0; 9]),
+            Err(ParseRenavamError::InvalidNumber)
+        );
+        assert_eq!(
+            Renavam::from_slice(&[0; 10]),
+            Err(ParseRenavamError::InvalidNumber)
+        );
+        assert_eq!(
+            Renavam::from_slice(&[0; 11]),
+            Err(ParseRenavamError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn as_bytes() {
+        let a: [u8; 11] = [6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3];
+        let b = Renavam([6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3]);
+
+        assert_eq!(&a, b.as_bytes());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Renavam::generate();
+        let b = a.to_string().parse::<Renavam>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn document() {
+        let a = Renavam([6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3]);
+
+        assert_eq!(&[6, 3, 9, 0, 2, 0, 7, 9, 0, 8], a.base());
+        assert_eq!(&[3], a.check_digits());
+        assert_eq!(
+            Ok([3]),
+            Renavam::compute_check_digits(&[6, 3, 9, 0, 2, 0, 7, 9, 0, 8])
+        );
+        assert_eq!(Renavam::FORMATTED_LEN, format!("{a}").len());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Renavam("63902079083")"#;
+        let b = Renavam([6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "63902079083".parse::<Renavam>().unwrap();
+        let b = "639020798".parse::<Renavam>().unwrap();
+
+        assert_eq!(a, Renavam([6, 3, 9, 0, 2, 0, 7, 9, 0, 8, 3]));
+        assert_eq!(b, Renavam([0, 0, 6, 3, 9, 0, 2, 0, 7, 9, 8]));
+        assert_eq!("".parse::<Renavam>(), Err(ParseRenavamError::Empty));
+        assert_eq!(
+            "6390207908-3".parse::<Renavam>(),
+            Err(ParseRenavamError::InvalidCharacter('-', 10))
+        );
+        assert_eq!(
+            "63902079084".parse::<Renavam>(),
+            Err(ParseRenavamError::InvalidNumber)
+        );
+        assert_eq!(
+            "6390207908".parse::<Renavam>(),
+            Err(ParseRenavamError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let renavam_str = "63902079083";
+        let renavam = Renavam::from_str(renavam_str).unwrap();
+        serde_test::assert_tokens(&renavam, &[serde_test::Token::Str(renavam_str)]);
+    }
+}
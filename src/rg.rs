@@ -0,0 +1,495 @@
+// rg.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::{Mod11, X};
+use crate::uf::Uf;
+
+// 2, 3, ..., 9, applied from left to right (SP and RJ)
+const CHECKSUM: Mod11 = Mod11::new(&[9, 8, 7, 6, 5, 4, 3, 2]).map_ten(X);
+
+/// An error which can be returned when parsing an [`Rg`].
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseRgError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+    InvalidUf,
+}
+
+impl fmt::Display for ParseRgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseRgError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid RG number"),
+            InvalidUf => write!(f, "invalid or missing state"),
+        }
+    }
+}
+
+impl core::error::Error for ParseRgError {}
+
+/// An _RG_ (_registro geral_, identity card) number, issued by a state.
+///
+/// Each state has its own format, and only SP and RJ publish the check digit algorithm; numbers
+/// from other states are accepted as they are. Parsing with [`Rg::parse`] recognizes numbers
+/// with or without separators (dot and minus), while [`FromStr`] also requires the issuer and
+/// the state, as in `12.345.678-X SSP/SP`.
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::{Rg, Uf};
+///
+/// let rg = "12.345.671-X SSP/SP".parse::<Rg>().expect("invalid RG");
+/// assert_eq!(Uf::Sp, rg.uf());
+/// assert_eq!(Some("SSP"), rg.issuer());
+/// assert!(rg.is_checked());
+///
+/// let rg = Rg::parse(Uf::Mg, "MG-12.345.678").expect("invalid RG");
+/// assert!(!rg.is_checked());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rg {
+    uf: Uf,
+    numbers: [u8; 14],
+    len: u8,
+    check_digit: Option<u8>,
+    issuer: [u8; 8],
+    issuer_len: u8,
+}
+
+impl Rg {
+    /// Parses a string as an RG number of the state, without the issuer.
+    ///
+    /// In SP and RJ, the last number is always the check digit. In the other states, the check
+    /// digit must be separated by a minus. A prefix with the abbreviation of the state, as in
+    /// `MG-12.345.678`, is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Rg, Uf};
+    ///
+    /// match Rg::parse(Uf::Sp, "12.345.678-2") {
+    ///     Ok(rg) => println!("{rg} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn parse(uf: Uf, s: &str) -> Result<Self, ParseRgError> {
+        let mut numbers = [0; 15];
+
+        if s.is_empty() {
+            return Err(ParseRgError::Empty);
+        }
+
+        // Skips the prefix with the state
+        let abbreviation = uf.abbreviation();
+        let skip = match s.as_bytes().get(..3) {
+            Some(prefix)
+                if prefix[..2].eq_ignore_ascii_case(abbreviation.as_bytes())
+                    && matches!(prefix[2], b'-' | b' ') =>
+            {
+                3
+            }
+            _ => 0,
+        };
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        let mut separator = None;
+        let mut chars = s.chars().enumerate().skip(skip).peekable();
+        while let Some((offset, ch)) = chars.next() {
+            match ch {
+                '0'..='9' | 'X' | 'x' if i < 15 => {
+                    // Only the check digit can be `X`
+                    if ch.is_ascii_digit() {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                    } else if chars.peek().is_none() {
+                        numbers[i] = X;
+                    } else {
+                        return Err(ParseRgError::InvalidCharacter(ch, offset));
+                    }
+                    i += 1;
+                }
+                '0'..='9' | 'X' | 'x' => return Err(ParseRgError::InvalidNumber),
+                '.' => continue,
+                '-' if separator.is_none() && chars.peek().is_some() => separator = Some(i),
+                _ => return Err(ParseRgError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        let has_check_digit = match separator {
+            // The check digit must be the last number
+            Some(separator) if separator + 1 != i => return Err(ParseRgError::InvalidNumber),
+            Some(_) => true,
+            None => has_known_check_digit(uf),
+        };
+
+        Self::new(uf, &numbers[..i], has_check_digit)
+    }
+
+    /// Parses a byte slice of numbers as an RG number of the state. In SP and RJ, the last
+    /// number is the check digit, and [`X`](crate::checksum::X) stands for `X`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Rg, Uf};
+    ///
+    /// match Rg::from_slice(Uf::Rj, &[1, 2, 3, 4, 5, 6, 7, 8, 2]) {
+    ///     Ok(rg) => println!("{rg} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(uf: Uf, slice: &[u8]) -> Result<Self, ParseRgError> {
+        if slice.is_empty() {
+            return Err(ParseRgError::Empty);
+        }
+
+        Self::new(uf, slice, has_known_check_digit(uf))
+    }
+
+    /// Returns the state that issued the RG.
+    #[inline]
+    pub fn uf(&self) -> Uf {
+        self.uf
+    }
+
+    /// Returns the issuing agency, as in `SSP` or `DETRAN`, if known.
+    #[inline]
+    pub fn issuer(&self) -> Option<&str> {
+        match self.issuer_len {
+            0 => None,
+            // SAFETY: Only ASCII letters are stored
+            len => Some(unsafe { core::str::from_utf8_unchecked(&self.issuer[..len as usize]) }),
+        }
+    }
+
+    /// Returns a byte slice of the numbers, without the check digit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Rg, Uf};
+    ///
+    /// let rg = Rg::parse(Uf::Sp, "12.345.678-2").expect("invalid RG");
+    /// assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], rg.as_bytes());
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.numbers[..self.len as usize]
+    }
+
+    /// Returns the check digit, if there is one. The `X` digit is represented by
+    /// [`X`](crate::checksum::X).
+    #[inline]
+    pub fn check_digit(&self) -> Option<u8> {
+        self.check_digit
+    }
+
+    /// Returns `true` if the check digit was validated, which is only possible in the states that
+    /// publish the algorithm.
+    #[inline]
+    pub fn is_checked(&self) -> bool {
+        has_known_check_digit(self.uf)
+    }
+
+    fn new(uf: Uf, slice: &[u8], has_check_digit: bool) -> Result<Self, ParseRgError> {
+        let (numbers, check_digit) = match slice.split_last() {
+            Some((&check_digit, numbers)) if has_check_digit => (numbers, Some(check_digit)),
+            _ => (slice, None),
+        };
+
+        // 0..=9, and X as the check digit
+        if numbers.iter().any(|&x| x > 9) || check_digit.is_some_and(|x| x > X) {
+            return Err(ParseRgError::InvalidNumber);
+        }
+
+        let len_range = if has_known_check_digit(uf) {
+            5..=8
+        } else {
+            3..=14
+        };
+        if !len_range.contains(&numbers.len()) {
+            return Err(ParseRgError::InvalidNumber);
+        }
+
+        // Checks for repeated numbers
+        let first_number = numbers[0];
+        if numbers.iter().all(|&x| x == first_number) {
+            return Err(ParseRgError::InvalidNumber);
+        }
+
+        if has_known_check_digit(uf) && check_digit != Some(CHECKSUM.check_digit(numbers)) {
+            return Err(ParseRgError::InvalidNumber);
+        }
+
+        let mut rg = Rg {
+            uf,
+            numbers: [0; 14],
+            len: numbers.len() as u8,
+            check_digit,
+            issuer: [0; 8],
+            issuer_len: 0,
+        };
+        rg.numbers[..numbers.len()].copy_from_slice(numbers);
+        Ok(rg)
+    }
+}
+
+impl AsRef<[u8]> for Rg {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl fmt::Debug for Rg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Rg(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Rg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Groups the numbers by thousands
+        let numbers = self.as_bytes();
+        for (i, number) in numbers.iter().enumerate() {
+            if i > 0 && (numbers.len() - i) % 3 == 0 {
+                f.write_char('.')?;
+            }
+            number.fmt(f)?;
+        }
+
+        match self.check_digit {
+            Some(X) => f.write_str("-X")?,
+            Some(check_digit) => write!(f, "-{check_digit}")?,
+            None => (),
+        }
+
+        match self.issuer() {
+            Some(issuer) => write!(f, " {issuer}/{}", self.uf),
+            None => write!(f, " {}", self.uf),
+        }
+    }
+}
+
+impl FromStr for Rg {
+    type Err = ParseRgError;
+
+    /// Parses a string as an RG number followed by the issuer and the state, as in
+    /// `12.345.678-X SSP/SP`, `12.345.678-X SSP-SP`, `12.345.678-X SSP SP` or
+    /// `12.345.678-X SP`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseRgError::Empty);
+        }
+
+        // Splits the number from the issuer and the state
+        let Some((number, suffix)) = s.split_once(' ') else {
+            return Err(ParseRgError::InvalidUf);
+        };
+        let suffix_offset = number.chars().count() + 1;
+
+        // The state is always the last two letters
+        let suffix_len = suffix.chars().count();
+        if suffix_len < 2 {
+            return Err(ParseRgError::InvalidUf);
+        }
+        let (issuer, uf) = suffix.split_at(suffix.char_indices().nth(suffix_len - 2).unwrap().0);
+        let uf = uf.parse::<Uf>().map_err(|_| ParseRgError::InvalidUf)?;
+
+        let mut rg = Self::parse(uf, number)?;
+
+        // Checks for invalid symbols and converts letters to upper case
+        let mut chars = issuer.chars().enumerate().peekable();
+        while let Some((offset, ch)) = chars.next() {
+            let i = rg.issuer_len as usize;
+            match ch {
+                'A'..='Z' | 'a'..='z' if i < 8 => {
+                    rg.issuer[i] = ch.to_ascii_uppercase() as u8;
+                    rg.issuer_len += 1;
+                }
+                '/' | '-' | ' ' if i > 0 && chars.peek().is_none() => (),
+                _ => {
+                    return Err(ParseRgError::InvalidCharacter(ch, suffix_offset + offset));
+                }
+            }
+        }
+
+        // The issuer must be separated from the state
+        if rg.issuer_len > 0
+            && issuer
+                .chars()
+                .last()
+                .is_some_and(|ch| ch.is_ascii_alphabetic())
+        {
+            return Err(ParseRgError::InvalidUf);
+        }
+
+        Ok(rg)
+    }
+}
+
+#[inline]
+fn has_known_check_digit(uf: Uf) -> bool {
+    matches!(uf, Uf::Sp | Uf::Rj)
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let a = Rg::parse(Uf::Sp, "12.345.678-2").unwrap();
+        let b = Rg::parse(Uf::Sp, "123456782").unwrap();
+        let c = Rg::parse(Uf::Rj, "12.345.671-x").unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], a.as_bytes());
+        assert_eq!(Some(2), a.check_digit());
+        assert_eq!(Some(X), c.check_digit());
+        assert_eq!(None, a.issuer());
+        assert!(a.is_checked());
+        assert!(Rg::parse(Uf::Sp, "1.234.567-2").is_ok());
+        assert_eq!(Rg::parse(Uf::Sp, ""), Err(ParseRgError::Empty));
+        assert_eq!(
+            Rg::parse(Uf::Sp, "12.345.678-3"),
+            Err(ParseRgError::InvalidNumber)
+        );
+        assert_eq!(
+            Rg::parse(Uf::Sp, "11.111.111-1"),
+            Err(ParseRgError::InvalidNumber)
+        );
+        assert_eq!(
+            Rg::parse(Uf::Sp, "12.345.67X-2"),
+            Err(ParseRgError::InvalidCharacter('X', 9))
+        );
+        assert_eq!(
+            Rg::parse(Uf::Sp, "12/345.678-2"),
+            Err(ParseRgError::InvalidCharacter('/', 2))
+        );
+        assert_eq!(
+            Rg::parse(Uf::Sp, "aé1"),
+            Err(ParseRgError::InvalidCharacter('a', 0))
+        );
+        assert_eq!(
+            Rg::parse(Uf::Sp, "Sé-12.345.678-2"),
+            Err(ParseRgError::InvalidCharacter('S', 0))
+        );
+    }
+
+    #[test]
+    fn parse_unchecked() {
+        let a = Rg::parse(Uf::Mg, "MG-12.345.678").unwrap();
+        let b = Rg::parse(Uf::Mg, "12345678").unwrap();
+        let c = Rg::parse(Uf::Ba, "12.345.678-9").unwrap();
+
+        assert_eq!(a, b);
+        assert!(!a.is_checked());
+        assert_eq!(None, a.check_digit());
+        assert_eq!(Some(9), c.check_digit());
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], c.as_bytes());
+        assert_eq!(
+            Rg::parse(Uf::Ba, "12-345.678-9"),
+            Err(ParseRgError::InvalidCharacter('-', 10))
+        );
+        assert_eq!(
+            Rg::parse(Uf::Ba, "1234567-89"),
+            Err(ParseRgError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn from_slice() {
+        let a = Rg::parse(Uf::Rj, "12.345.671-X").unwrap();
+
+        assert_eq!(Ok(a), Rg::from_slice(Uf::Rj, &[1, 2, 3, 4, 5, 6, 7, 1, X]));
+        assert_eq!(
+            Rg::from_slice(Uf::Rj, &[1, 2, 3, 4, 5, 6, 7, 1, 0]),
+            Err(ParseRgError::InvalidNumber)
+        );
+        assert_eq!(
+            Rg::from_slice(Uf::Mg, &[1, 2, 3, X, 5, 6, 7, 8]),
+            Err(ParseRgError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Rg("12.345.671-X SSP/SP")"#;
+        let b = "12.345.671-X SSP/SP".parse::<Rg>().unwrap();
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = Rg::parse(Uf::Sp, "123456782").unwrap();
+        let b = Rg::parse(Uf::Mg, "MG-1.234.567").unwrap();
+
+        assert_eq!("12.345.678-2 SP", format!("{a}"));
+        assert_eq!("1.234.567 MG", format!("{b}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "12.345.671-X SSP/SP".parse::<Rg>().unwrap();
+
+        assert_eq!(Uf::Sp, a.uf());
+        assert_eq!(Some("SSP"), a.issuer());
+        assert_eq!(Some(X), a.check_digit());
+        assert_eq!(Ok(a), "12345671x ssp-sp".parse());
+        assert_eq!(Ok(a), "12.345.671-X SSP SP".parse());
+        assert_eq!(Ok(a), format!("{a}").parse());
+
+        let b = "12.345.678 PC/MG".parse::<Rg>().unwrap();
+        assert_eq!(Ok(b), format!("{b}").parse());
+        assert_eq!(Some("PC"), b.issuer());
+
+        let c = "12.345.678-2 SP".parse::<Rg>().unwrap();
+        assert_eq!(None, c.issuer());
+        assert_eq!(Ok(c), format!("{c}").parse());
+
+        assert_eq!("".parse::<Rg>(), Err(ParseRgError::Empty));
+        assert_eq!("12.345.678-2".parse::<Rg>(), Err(ParseRgError::InvalidUf));
+        assert_eq!(
+            "12.345.678-2 SSP/ZZ".parse::<Rg>(),
+            Err(ParseRgError::InvalidUf)
+        );
+        assert_eq!(
+            "12.345.678-2 SSPSP".parse::<Rg>(),
+            Err(ParseRgError::InvalidUf)
+        );
+        assert_eq!(
+            "12.345.678-2 S5P/SP".parse::<Rg>(),
+            Err(ParseRgError::InvalidCharacter('5', 14))
+        );
+        assert_eq!(
+            "12.345.678-3 SSP/SP".parse::<Rg>(),
+            Err(ParseRgError::InvalidNumber)
+        );
+    }
+}
@@ -0,0 +1,378 @@
+// suframa.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 9, 8, 7, 6, 5, 4, 3, 2, applied from left to right
+const CHECKSUM: Mod11 = Mod11::new(&[2, 3, 4, 5, 6, 7, 8, 9]);
+
+// Types of company
+const PREFIXES: [[u8; 2]; 5] = [[0, 1], [1, 0], [1, 1], [2, 0], [6, 0]];
+
+/// An error which can be returned when parsing a [`Suframa`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseSuframaError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseSuframaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseSuframaError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid SUFRAMA number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseSuframaError {}
+
+/// A valid SUFRAMA registration number, held by companies in the Manaus Free Trade Zone along
+/// with their [`Cnpj`](crate::Cnpj), and reported in NF-e. The first two numbers are the type of
+/// company: `01`, `10`, `11`, `20` or `60`. Parsing recognizes numbers with or without
+/// separators (dot).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Suframa([u8; 9]);
+
+impl Suframa {
+    /// Parses a byte slice of numbers as a SUFRAMA registration, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::Suframa;
+    ///
+    /// match Suframa::from_slice(&[2, 0, 0, 1, 2, 3, 4, 5, 9]) {
+    ///     Ok(suframa) => println!("{suframa} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digit:
+    ///
+    /// ```rust
+    /// use brids::Suframa;
+    ///
+    /// match Suframa::from_slice(&[2, 0, 0, 1, 2, 3, 4, 5]) {
+    ///     Ok(suframa) => println!("{suframa} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseSuframaError> {
+        let mut numbers = [0; 9];
+        match slice.len() {
+            0 => return Err(ParseSuframaError::Empty),
+            len @ (8 | 9) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseSuframaError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseSuframaError::InvalidNumber);
+        }
+
+        // Checks the type of company
+        if !PREFIXES.contains(&[numbers[0], numbers[1]]) {
+            return Err(ParseSuframaError::InvalidNumber);
+        }
+
+        let check_digit = CHECKSUM.check_digit(&numbers[..8]);
+        if slice.len() == 8 {
+            numbers[8] = check_digit;
+        } else if check_digit != numbers[8] {
+            return Err(ParseSuframaError::InvalidNumber);
+        }
+
+        Ok(Suframa(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Suframa;
+    ///
+    /// let suframa = "20.0123.459".parse::<Suframa>().expect("invalid SUFRAMA");
+    /// let digits = suframa.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 9] {
+        &self.0
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Suframa;
+    ///
+    /// let suframa = Suframa::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl Document for Suframa {
+    const NAME: &'static str = "SUFRAMA";
+    const LEN: usize = 9;
+    const FORMATTED_LEN: usize = 11;
+
+    type Error = ParseSuframaError;
+    type CheckDigits = [u8; 1];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..8]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[8..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 8) {
+            return Err(ParseSuframaError::InvalidNumber);
+        }
+
+        Ok([CHECKSUM.check_digit(base)])
+    }
+}
+
+impl AsRef<[u8]> for Suframa {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<Suframa> for [u8; 9] {
+    #[inline]
+    fn from(suframa: Suframa) -> [u8; 9] {
+        suframa.0
+    }
+}
+
+impl TryFrom<&[u8]> for Suframa {
+    type Error = ParseSuframaError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 9]> for Suframa {
+    type Error = ParseSuframaError;
+
+    #[inline]
+    fn try_from(value: &[u8; 9]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Suframa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Suframa(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Suframa {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            match i {
+                2 | 6 => f.write_char('.')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Suframa {
+    type Err = ParseSuframaError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 9];
+
+        if s.is_empty() {
+            return Err(ParseSuframaError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 9 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseSuframaError::InvalidNumber);
+                    }
+                }
+                ('.', 2 | 7) => continue,
+                _ => return Err(ParseSuframaError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 9 {
+            return Err(ParseSuframaError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Suframa> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Suframa {
+        let mut numbers = [0; 9];
+        numbers[..2].copy_from_slice(&PREFIXES[rng.random_range(0..PREFIXES.len())]);
+        for number in &mut numbers[2..8] {
+            *number = rng.random_range(0..=9);
+        }
+
+        numbers[8] = CHECKSUM.check_digit(&numbers[..8]); // check digit
+
+        Suframa(numbers)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Suframa {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Suframa {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Suframa([2, 0, 0, 1, 2, 3, 4, 5, 9]);
+
+        assert_eq!(Ok(a), Suframa::from_slice(&a.0));
+        assert_eq!(Ok(a), Suframa::from_slice(&a.0[..8]));
+        assert!(Suframa::from_slice(&[1, 0, 1, 2, 3, 4, 5, 6, 2]).is_ok());
+        assert!(Suframa::from_slice(&[6, 0, 1, 2, 3, 4, 5, 6, 1]).is_ok());
+        assert_eq!(
+            Suframa::from_slice(&[2, 0, 0, 1, 2, 3, 4, 5, 8]),
+            Err(ParseSuframaError::InvalidNumber)
+        );
+        assert_eq!(
+            Suframa::from_slice(&[3, 0, 0, 1, 2, 3, 4, 5, 0]),
+            Err(ParseSuframaError::InvalidNumber)
+        );
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Suframa::generate();
+        let b = a.to_string().parse::<Suframa>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Suframa("20.0123.459")"#;
+        let b = Suframa([2, 0, 0, 1, 2, 3, 4, 5, 9]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "20.0123.459".parse::<Suframa>().unwrap();
+        let b = "200123459".parse::<Suframa>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!("".parse::<Suframa>(), Err(ParseSuframaError::Empty));
+        assert_eq!(
+            "20.0123-459".parse::<Suframa>(),
+            Err(ParseSuframaError::InvalidCharacter('-', 7))
+        );
+        assert_eq!(
+            "20.0123.458".parse::<Suframa>(),
+            Err(ParseSuframaError::InvalidNumber)
+        );
+        assert_eq!(
+            "20.0123.45".parse::<Suframa>(),
+            Err(ParseSuframaError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let suframa_str = "20.0123.459";
+        let suframa = Suframa::from_str(suframa_str).unwrap();
+        serde_test::assert_tokens(&suframa, &[serde_test::Token::Str(suframa_str)]);
+    }
+}
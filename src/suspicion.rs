@@ -0,0 +1,161 @@
+// suspicion.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::fmt;
+use core::ops::{BitOr, BitOrAssign};
+
+/// A set of reasons to suspect that a valid number is a placeholder or a fraud, as returned by
+/// [`Cpf::suspicion`](crate::Cpf::suspicion) and [`Cnpj::suspicion`](crate::Cnpj::suspicion).
+///
+/// # Examples
+///
+/// ```rust
+/// use brids::{Cpf, Suspicion};
+///
+/// let cpf = "123.456.789-09".parse::<Cpf>().expect("invalid CPF");
+/// let suspicion = cpf.suspicion();
+///
+/// assert!(suspicion.contains(Suspicion::SEQUENTIAL | Suspicion::KNOWN_EXAMPLE));
+/// assert!(!suspicion.contains(Suspicion::LOW_ENTROPY));
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Suspicion(u8);
+
+impl Suspicion {
+    /// The numbers are in ascending or descending order, as in `123.456.789-09`.
+    pub const SEQUENTIAL: Self = Self(1);
+
+    /// The numbers have few distinct digits or are a palindrome, as in `000.000.001-91`.
+    pub const LOW_ENTROPY: Self = Self(1 << 1);
+
+    /// The number is used as an example in documentation, including this crate's.
+    pub const KNOWN_EXAMPLE: Self = Self(1 << 2);
+
+    /// The number is widely used in tests and sandbox environments.
+    pub const KNOWN_TEST_NUMBER: Self = Self(1 << 3);
+
+    /// The number is in a list provided by the caller.
+    pub const LISTED: Self = Self(1 << 4);
+
+    const NAMES: [(Self, &'static str); 5] = [
+        (Self::SEQUENTIAL, "SEQUENTIAL"),
+        (Self::LOW_ENTROPY, "LOW_ENTROPY"),
+        (Self::KNOWN_EXAMPLE, "KNOWN_EXAMPLE"),
+        (Self::KNOWN_TEST_NUMBER, "KNOWN_TEST_NUMBER"),
+        (Self::LISTED, "LISTED"),
+    ];
+
+    /// Returns an empty set.
+    #[inline]
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Checks if the set is empty, that is, if there is no reason for suspicion.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Checks if the set contains all the reasons of `other`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Adds the reasons of `other` to the set.
+    #[inline]
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+}
+
+impl BitOr for Suspicion {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Suspicion {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.insert(rhs);
+    }
+}
+
+impl fmt::Debug for Suspicion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Suspicion(")?;
+        let mut first = true;
+        for (flag, name) in Self::NAMES {
+            if self.contains(flag) {
+                if !first {
+                    f.write_str(" | ")?;
+                }
+                f.write_str(name)?;
+                first = false;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+/// Analyzes the pattern of the numbers, without the check digits.
+pub(crate) fn analyze(base: &[u8]) -> Suspicion {
+    let mut suspicion = Suspicion::empty();
+
+    // Ascending or descending, wrapping around 9 and 0
+    let is_sequential = |step: u8| base.windows(2).all(|x| (x[0] + step) % 10 == x[1]);
+    if is_sequential(1) || is_sequential(9) {
+        suspicion |= Suspicion::SEQUENTIAL;
+    }
+
+    let distinct = (0..=9).filter(|digit| base.contains(digit)).count();
+    let is_palindrome = base.iter().eq(base.iter().rev());
+    if distinct <= 2 || is_palindrome {
+        suspicion |= Suspicion::LOW_ENTROPY;
+    }
+
+    suspicion
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn patterns() {
+        let sequential = Suspicion::SEQUENTIAL;
+        let low_entropy = Suspicion::LOW_ENTROPY;
+
+        assert_eq!(sequential, analyze(&[1, 2, 3, 4, 5, 6, 7, 8, 9]));
+        assert_eq!(sequential, analyze(&[9, 8, 7, 6, 5, 4, 3, 2, 1]));
+        assert_eq!(sequential, analyze(&[8, 9, 0, 1, 2, 3, 4, 5, 6]));
+        assert_eq!(low_entropy, analyze(&[0, 0, 0, 0, 0, 0, 0, 0, 1]));
+        assert_eq!(low_entropy, analyze(&[1, 2, 3, 4, 5, 4, 3, 2, 1]));
+        assert!(analyze(&[5, 2, 9, 9, 8, 2, 2, 4, 7]).is_empty());
+    }
+
+    #[test]
+    fn debug() {
+        let a = Suspicion::SEQUENTIAL | Suspicion::KNOWN_EXAMPLE;
+
+        assert_eq!("Suspicion(SEQUENTIAL | KNOWN_EXAMPLE)", format!("{a:?}"));
+        assert_eq!("Suspicion()", format!("{:?}", Suspicion::empty()));
+    }
+}
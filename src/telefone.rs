@@ -0,0 +1,483 @@
+// telefone.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::uf::Uf;
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+/// An error which can be returned when parsing a [`Telefone`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseTelefoneError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+    /// The area code (DDD) is not in use.
+    InvalidDdd(u8),
+}
+
+impl fmt::Display for ParseTelefoneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseTelefoneError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid phone number"),
+            InvalidDdd(ddd) => write!(f, "invalid DDD `{ddd}`"),
+        }
+    }
+}
+
+impl core::error::Error for ParseTelefoneError {}
+
+/// A valid Brazilian phone number, with the area code (DDD).
+///
+/// Parsing recognizes numbers with or without separators (space, parentheses, minus and dot),
+/// with or without the country code (`+55`), and with the `0` prefix, optionally followed by a
+/// carrier code, as in `0 21 11 91234-5678`. Mobile numbers must have 9 digits, starting with 9,
+/// and landline numbers must have 8 digits, starting with 2 to 5.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Telefone {
+    numbers: [u8; 11],
+    len: u8,
+}
+
+impl Telefone {
+    /// Parses a byte slice of numbers as a phone number, including the DDD.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Telefone;
+    ///
+    /// match Telefone::from_slice(&[1, 1, 9, 1, 2, 3, 4, 5, 6, 7, 8]) {
+    ///     Ok(telefone) => println!("{telefone} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseTelefoneError> {
+        let mut numbers = [0; 11];
+        match slice.len() {
+            0 => return Err(ParseTelefoneError::Empty),
+            len @ (10 | 11) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseTelefoneError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseTelefoneError::InvalidNumber);
+        }
+
+        let ddd = numbers[0] * 10 + numbers[1];
+        if ddd_to_uf(ddd).is_none() {
+            return Err(ParseTelefoneError::InvalidDdd(ddd));
+        }
+
+        // Mobile numbers have the 9th digit, landline numbers start with 2 to 5
+        match (slice.len(), numbers[2]) {
+            (11, 9) | (10, 2..=5) => (),
+            _ => return Err(ParseTelefoneError::InvalidNumber),
+        }
+
+        Ok(Telefone {
+            numbers,
+            len: slice.len() as u8,
+        })
+    }
+
+    /// Returns a byte slice of the numbers, including the DDD.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Telefone;
+    ///
+    /// let telefone = "(11) 91234-5678".parse::<Telefone>().expect("invalid phone number");
+    /// let digits = telefone.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.numbers[..self.len as usize]
+    }
+
+    /// Returns the area code (DDD).
+    #[inline]
+    pub fn ddd(&self) -> u8 {
+        self.numbers[0] * 10 + self.numbers[1]
+    }
+
+    /// Returns the state of the DDD.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Telefone, Uf};
+    ///
+    /// let telefone = "(61) 3456-7890".parse::<Telefone>().expect("invalid phone number");
+    /// assert_eq!(Uf::Df, telefone.uf());
+    /// ```
+    pub fn uf(&self) -> Uf {
+        match ddd_to_uf(self.ddd()) {
+            Some(uf) => uf,
+            None => unreachable!("DDD already checked"),
+        }
+    }
+
+    /// Returns `true` if the number is a mobile number.
+    #[inline]
+    pub fn is_mobile(&self) -> bool {
+        self.len == 11
+    }
+
+    /// Returns an object that displays the number in the E.164 format, as in `+5511912345678`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Telefone;
+    ///
+    /// let telefone = "(11) 91234-5678".parse::<Telefone>().expect("invalid phone number");
+    /// assert_eq!("+5511912345678", telefone.e164().to_string());
+    /// ```
+    pub fn e164(&self) -> impl fmt::Display + '_ {
+        E164(self)
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Telefone;
+    ///
+    /// let telefone = Telefone::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+}
+
+impl AsRef<[u8]> for Telefone {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for Telefone {
+    type Error = ParseTelefoneError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for Telefone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Telefone(\"{self}\")")
+    }
+}
+
+impl fmt::Display for Telefone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let hyphen = self.len as usize - 4;
+        for (i, number) in self.as_bytes().iter().enumerate() {
+            match i {
+                0 => f.write_char('(')?,
+                2 => f.write_str(") ")?,
+                _ if i == hyphen => f.write_char('-')?,
+                _ => (),
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+struct E164<'a>(&'a Telefone);
+
+impl fmt::Display for E164<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("+55")?;
+        for number in self.0.as_bytes() {
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Telefone {
+    type Err = ParseTelefoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 14];
+
+        if s.is_empty() {
+            return Err(ParseTelefoneError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        let mut has_plus = false;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 14 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseTelefoneError::InvalidNumber);
+                    }
+                }
+                ('+', 0) => has_plus = true,
+                (' ' | '(' | ')' | '-' | '.', _) => continue,
+                _ => return Err(ParseTelefoneError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Removes the prefixes
+        let numbers = match &numbers[..i] {
+            [5, 5, rest @ ..] if has_plus => rest,
+            _ if has_plus => return Err(ParseTelefoneError::InvalidNumber),
+            [0, rest @ ..] if rest.len() > 11 => &rest[2..], // Carrier code
+            [0, rest @ ..] => rest,
+            [5, 5, rest @ ..] if rest.len() >= 10 => rest,
+            numbers => numbers,
+        };
+
+        Self::from_slice(numbers)
+    }
+}
+
+/// Returns the state of the DDD, if it is in use.
+fn ddd_to_uf(ddd: u8) -> Option<Uf> {
+    use Uf::*;
+    let uf = match ddd {
+        11..=19 => Sp,
+        21 | 22 | 24 => Rj,
+        27 | 28 => Es,
+        31..=35 | 37 | 38 => Mg,
+        41..=46 => Pr,
+        47..=49 => Sc,
+        51 | 53..=55 => Rs,
+        61 => Df,
+        62 | 64 => Go,
+        63 => To,
+        65 | 66 => Mt,
+        67 => Ms,
+        68 => Ac,
+        69 => Ro,
+        71 | 73..=75 | 77 => Ba,
+        79 => Se,
+        81 | 87 => Pe,
+        82 => Al,
+        83 => Pb,
+        84 => Rn,
+        85 | 88 => Ce,
+        86 | 89 => Pi,
+        91 | 93 | 94 => Pa,
+        92 | 97 => Am,
+        95 => Rr,
+        96 => Ap,
+        98 | 99 => Ma,
+        _ => return None,
+    };
+    Some(uf)
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<Telefone> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Telefone {
+        let mut numbers = [0; 11];
+        loop {
+            numbers[0] = rng.random_range(1..=9);
+            numbers[1] = rng.random_range(1..=9);
+            if ddd_to_uf(numbers[0] * 10 + numbers[1]).is_some() {
+                break;
+            }
+        }
+
+        let len = if rng.random() {
+            numbers[2] = 9;
+            11
+        } else {
+            numbers[2] = rng.random_range(2..=5);
+            10
+        };
+        for number in &mut numbers[3..len] {
+            *number = rng.random_range(0..=9);
+        }
+
+        Telefone {
+            numbers,
+            len: len as u8,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Telefone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(not(feature = "std"))]
+        use crate::alloc::string::ToString;
+
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Telefone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TelefoneStringVisitor;
+
+        impl de::Visitor<'_> for TelefoneStringVisitor {
+            type Value = Telefone;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a phone number string")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Telefone, E> {
+                value.parse().map_err(E::custom)
+            }
+
+            fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Telefone, E> {
+                Telefone::from_slice(value).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(TelefoneStringVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = Telefone::from_slice(&[1, 1, 9, 1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+        let b = Telefone::from_slice(&[2, 1, 3, 4, 5, 6, 7, 8, 9, 0]).unwrap();
+
+        assert!(a.is_mobile());
+        assert!(!b.is_mobile());
+        assert_eq!(
+            Telefone::from_slice(&[1, 1, 8, 1, 2, 3, 4, 5, 6, 7, 8]),
+            Err(ParseTelefoneError::InvalidNumber)
+        );
+        assert_eq!(
+            Telefone::from_slice(&[1, 1, 9, 1, 2, 3, 4, 5, 6, 7]),
+            Err(ParseTelefoneError::InvalidNumber)
+        );
+        assert_eq!(
+            Telefone::from_slice(&[2, 0, 3, 4, 5, 6, 7, 8, 9, 0]),
+            Err(ParseTelefoneError::InvalidDdd(20))
+        );
+    }
+
+    #[test]
+    fn uf() {
+        assert_eq!(Some(Uf::Sp), ddd_to_uf(11));
+        assert_eq!(Some(Uf::Rs), ddd_to_uf(55));
+        assert_eq!(Some(Uf::Ma), ddd_to_uf(99));
+        assert_eq!(None, ddd_to_uf(23));
+        assert_eq!(None, ddd_to_uf(10));
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = Telefone::generate();
+        let b = a.to_string().parse::<Telefone>().unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"Telefone("(11) 91234-5678")"#;
+        let b = "11912345678".parse::<Telefone>().unwrap();
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = "11912345678".parse::<Telefone>().unwrap();
+        let b = "2134567890".parse::<Telefone>().unwrap();
+
+        assert_eq!("(11) 91234-5678", format!("{a}"));
+        assert_eq!("(21) 3456-7890", format!("{b}"));
+        assert_eq!("+5511912345678", format!("{}", a.e164()));
+        assert_eq!("+552134567890", format!("{}", b.e164()));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "(11) 91234-5678".parse::<Telefone>().unwrap();
+
+        assert_eq!(a, "+55 11 912345678".parse().unwrap());
+        assert_eq!(a, "+55 (11) 91234-5678".parse().unwrap());
+        assert_eq!(a, "5511912345678".parse().unwrap());
+        assert_eq!(a, "011 91234-5678".parse().unwrap());
+        assert_eq!(a, "0 21 11 91234-5678".parse().unwrap());
+        assert_eq!(a, "11.91234.5678".parse().unwrap());
+        assert_eq!(11, a.ddd());
+        assert_eq!("(55) 3456-7890".parse::<Telefone>().unwrap().uf(), Uf::Rs);
+        assert_eq!("".parse::<Telefone>(), Err(ParseTelefoneError::Empty));
+        assert_eq!(
+            "11 91234+5678".parse::<Telefone>(),
+            Err(ParseTelefoneError::InvalidCharacter('+', 8))
+        );
+        assert_eq!(
+            "+1 11 912345678".parse::<Telefone>(),
+            Err(ParseTelefoneError::InvalidNumber)
+        );
+        assert_eq!(
+            "(11) 1234-5678".parse::<Telefone>(),
+            Err(ParseTelefoneError::InvalidNumber)
+        );
+        assert_eq!(
+            "(10) 91234-5678".parse::<Telefone>(),
+            Err(ParseTelefoneError::InvalidDdd(10))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let telefone_str = "(11) 91234-5678";
+        let telefone = Telefone::from_str(telefone_str).unwrap();
+        serde_test::assert_tokens(&telefone, &[serde_test::Token::Str(telefone_str)]);
+    }
+}
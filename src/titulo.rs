@@ -0,0 +1,513 @@
+// titulo.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt::{self, Write};
+use core::str::FromStr;
+
+use crate::Uf;
+use crate::checksum::Mod11;
+use crate::document::{self, Document};
+
+#[cfg(feature = "rand")]
+use rand::{
+    Rng,
+    distr::{Distribution, StandardUniform},
+};
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+// 2, 3, 4, 5, 6, 7, 8, 9 from left to right; the second check digit uses the last weights
+const CHECKSUM: Mod11 = Mod11::new(&[9, 8, 7, 6, 5, 4, 3, 2]).remainder();
+
+// Electoral codes, from `01` to `27`; `28` is for voters abroad
+const UFS: [Uf; 27] = {
+    use Uf::*;
+    [
+        Sp, Mg, Rj, Rs, Ba, Pr, Ce, Pe, Sc, Go, Ma, Pb, Pa, Es, Pi, Rn, Al, Mt, Ms, Df, Se, Am, Ro,
+        Ac, Ap, Rr, To,
+    ]
+};
+
+const ABROAD: u8 = 28;
+
+/// An error which can be returned when parsing an [`TituloEleitor`] number.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseTituloEleitorError {
+    Empty,
+    InvalidCharacter(char, usize),
+    InvalidNumber,
+}
+
+impl fmt::Display for ParseTituloEleitorError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseTituloEleitorError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidCharacter(ch, offset) => {
+                write!(f, "invalid character `{ch}` at offset {offset}")
+            }
+            InvalidNumber => write!(f, "invalid título de eleitor number"),
+        }
+    }
+}
+
+impl core::error::Error for ParseTituloEleitorError {}
+
+/// A valid título de eleitor (voter ID) number. Parsing recognizes numbers with or without
+/// separators (space).
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TituloEleitor([u8; 12]);
+
+impl TituloEleitor {
+    /// Parses a byte slice of numbers as a título de eleitor, guessing the missing parts.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```rust
+    /// use brids::TituloEleitor;
+    ///
+    /// match TituloEleitor::from_slice(&[0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6]) {
+    ///     Ok(titulo) => println!("{titulo} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    ///
+    /// Guess the check digits:
+    ///
+    /// ```rust
+    /// use brids::TituloEleitor;
+    ///
+    /// match TituloEleitor::from_slice(&[0, 0, 4, 3, 5, 6, 8, 7, 0, 9]) {
+    ///     Ok(titulo) => println!("{titulo} is a valid number."),
+    ///     Err(err) => eprintln!("Error: {err}"),
+    /// }
+    /// ```
+    pub fn from_slice(slice: &[u8]) -> Result<Self, ParseTituloEleitorError> {
+        let mut numbers = [0; 12];
+        match slice.len() {
+            0 => return Err(ParseTituloEleitorError::Empty),
+            len @ (10 | 12) => numbers[..len].copy_from_slice(slice),
+            _ => return Err(ParseTituloEleitorError::InvalidNumber),
+        }
+
+        // 0..=9
+        if numbers.iter().any(|&x| x > 9) {
+            return Err(ParseTituloEleitorError::InvalidNumber);
+        }
+
+        if !(1..=ABROAD).contains(&uf_code(&numbers)) {
+            return Err(ParseTituloEleitorError::InvalidNumber);
+        }
+
+        let check_digits = calc_check_digits(&numbers);
+        if slice.len() < 12 {
+            numbers[10..].copy_from_slice(&check_digits);
+        } else if check_digits != numbers[10..] {
+            return Err(ParseTituloEleitorError::InvalidNumber);
+        }
+
+        Ok(TituloEleitor(numbers))
+    }
+
+    /// Returns a byte slice of the numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::TituloEleitor;
+    ///
+    /// let titulo = "0043 5687 0906".parse::<TituloEleitor>().expect("invalid título");
+    /// let digits = titulo.as_bytes();
+    /// ```
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; 12] {
+        &self.0
+    }
+
+    /// Returns the state where the voter is registered, or `None` for voters abroad.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{TituloEleitor, Uf};
+    ///
+    /// let titulo = "0043 5687 0906".parse::<TituloEleitor>().expect("invalid título");
+    /// assert_eq!(Some(Uf::Sc), titulo.uf());
+    /// ```
+    #[inline]
+    pub fn uf(&self) -> Option<Uf> {
+        UFS.get(usize::from(uf_code(&self.0)) - 1).copied()
+    }
+
+    /// Checks if the voter is registered abroad (code `28`, also known as `ZZ`).
+    #[inline]
+    pub fn is_abroad(&self) -> bool {
+        uf_code(&self.0) == ABROAD
+    }
+
+    /// Generates a random number of a state, or of voters abroad if `uf` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{TituloEleitor, Uf};
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(123);
+    /// let titulo = TituloEleitor::random_in(&mut rng, Some(Uf::Mg));
+    /// assert_eq!(Some(Uf::Mg), titulo.uf());
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random_in<R: Rng + ?Sized>(rng: &mut R, uf: Option<Uf>) -> Self {
+        let code = match uf {
+            // All states have an electoral code
+            Some(uf) => UFS.iter().position(|&x| x == uf).unwrap() as u8 + 1,
+            None => ABROAD,
+        };
+
+        let mut numbers = [0; 12];
+        for number in &mut numbers[..8] {
+            *number = rng.random_range(0..=9);
+        }
+        numbers[8] = code / 10;
+        numbers[9] = code % 10;
+
+        let check_digits = calc_check_digits(&numbers);
+        numbers[10..].copy_from_slice(&check_digits);
+
+        TituloEleitor(numbers)
+    }
+
+    /// Generates a random number, using [`rand::rng`] (requires `std` and `rand` features).
+    /// To use a different generator, instantiate the generator directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::TituloEleitor;
+    ///
+    /// let titulo = TituloEleitor::generate();
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate() -> Self {
+        rand::rng().random()
+    }
+
+    /// Generates a random number of a state, or of voters abroad if `uf` is `None`, using
+    /// [`rand::rng`] (requires `std` and `rand` features).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{TituloEleitor, Uf};
+    ///
+    /// let titulo = TituloEleitor::generate_in(Some(Uf::Sp));
+    /// ```
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[inline]
+    pub fn generate_in(uf: Option<Uf>) -> Self {
+        Self::random_in(&mut rand::rng(), uf)
+    }
+}
+
+impl Document for TituloEleitor {
+    const NAME: &'static str = "título de eleitor";
+    const LEN: usize = 12;
+    const FORMATTED_LEN: usize = 14;
+
+    type Error = ParseTituloEleitorError;
+    type CheckDigits = [u8; 2];
+
+    #[inline]
+    fn from_digits(digits: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(digits)
+    }
+
+    #[inline]
+    fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[inline]
+    fn base(&self) -> &[u8] {
+        &self.0[..10]
+    }
+
+    #[inline]
+    fn check_digits(&self) -> &[u8] {
+        &self.0[10..]
+    }
+
+    fn compute_check_digits(base: &[u8]) -> Result<Self::CheckDigits, Self::Error> {
+        if !document::check_base(base, 10) || !(1..=ABROAD).contains(&uf_code(base)) {
+            return Err(ParseTituloEleitorError::InvalidNumber);
+        }
+
+        Ok(calc_check_digits(base))
+    }
+}
+
+impl AsRef<[u8]> for TituloEleitor {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl From<TituloEleitor> for [u8; 12] {
+    #[inline]
+    fn from(titulo: TituloEleitor) -> [u8; 12] {
+        titulo.0
+    }
+}
+
+impl TryFrom<&[u8]> for TituloEleitor {
+    type Error = ParseTituloEleitorError;
+
+    #[inline]
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl TryFrom<&[u8; 12]> for TituloEleitor {
+    type Error = ParseTituloEleitorError;
+
+    #[inline]
+    fn try_from(value: &[u8; 12]) -> Result<Self, Self::Error> {
+        Self::from_slice(value)
+    }
+}
+
+impl fmt::Debug for TituloEleitor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "TituloEleitor(\"{self}\")")
+    }
+}
+
+impl fmt::Display for TituloEleitor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, number) in self.0.iter().enumerate() {
+            if let 4 | 8 = i {
+                f.write_char(' ')?;
+            }
+            number.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for TituloEleitor {
+    type Err = ParseTituloEleitorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut numbers = [0; 12];
+
+        if s.is_empty() {
+            return Err(ParseTituloEleitorError::Empty);
+        }
+
+        // Checks for invalid symbols and converts numbers to integers
+        let mut i = 0;
+        let mut has_space = false;
+        for (offset, ch) in s.chars().enumerate() {
+            match (ch, offset) {
+                ('0'..='9', _) => {
+                    if i < 12 {
+                        // SAFETY: Digit already matched
+                        numbers[i] = unsafe { ch.to_digit(10).unwrap_unchecked() as u8 };
+                        i += 1;
+                    } else {
+                        return Err(ParseTituloEleitorError::InvalidNumber);
+                    }
+                }
+                (' ', 4) => has_space = true,
+                (' ', 9) if has_space => continue,
+                _ => return Err(ParseTituloEleitorError::InvalidCharacter(ch, offset)),
+            }
+        }
+
+        // Checks the length
+        if i != 12 {
+            return Err(ParseTituloEleitorError::InvalidNumber);
+        }
+
+        Self::from_slice(&numbers)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl Distribution<TituloEleitor> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> TituloEleitor {
+        let uf = UFS.get(rng.random_range(0..=UFS.len())).copied();
+        TituloEleitor::random_in(rng, uf)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for TituloEleitor {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        document::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for TituloEleitor {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        document::deserialize(deserializer)
+    }
+}
+
+#[inline]
+fn uf_code(numbers: &[u8]) -> u8 {
+    numbers[8] * 10 + numbers[9]
+}
+
+fn calc_check_digits(numbers: &[u8]) -> [u8; 2] {
+    // In SP and MG, the remainder `0` becomes `1`
+    let is_sp_mg = matches!(uf_code(numbers), 1 | 2);
+    let check_digit = |numbers: &[u8]| {
+        let sum = CHECKSUM.sum(numbers);
+        match CHECKSUM.digit_from_sum(sum) {
+            0 if is_sp_mg && sum % 11 == 0 => 1,
+            digit => digit,
+        }
+    };
+
+    let first = check_digit(&numbers[..8]);
+    let second = check_digit(&[numbers[8], numbers[9], first]);
+    [first, second]
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn from_slice() {
+        let a = TituloEleitor([0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6]);
+        let b: [u8; 12] = [0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6];
+        let c: [u8; 10] = [0, 0, 4, 3, 5, 6, 8, 7, 0, 9];
+
+        assert_eq!(a, TituloEleitor::from_slice(&b).unwrap());
+        assert_eq!(a, TituloEleitor::from_slice(&c).unwrap());
+        assert_eq!(
+            TituloEleitor::from_slice(&[0, 0, 4, 3, 5, 6, 8, 7, 2, 9]),
+            Err(ParseTituloEleitorError::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn check_digits() {
+        // SP, where the remainder `0` becomes `1`
+        assert_eq!([1, 6], calc_check_digits(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 1]));
+        // RJ
+        assert_eq!([0, 2], calc_check_digits(&[0, 0, 0, 0, 0, 0, 0, 0, 0, 3]));
+    }
+
+    #[test]
+    fn uf() {
+        let a = TituloEleitor::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 2]).unwrap();
+        let b = TituloEleitor::from_slice(&[1, 2, 3, 4, 5, 6, 7, 8, 2, 8]).unwrap();
+
+        assert_eq!(Some(Uf::Mg), a.uf());
+        assert!(!a.is_abroad());
+        assert_eq!(None, b.uf());
+        assert!(b.is_abroad());
+    }
+
+    #[cfg(all(feature = "std", feature = "rand"))]
+    #[test]
+    fn generate() {
+        let a = TituloEleitor::generate();
+        let b = a.to_string().parse::<TituloEleitor>().unwrap();
+
+        assert_eq!(a, b);
+
+        for uf in Uf::ALL.into_iter().map(Some).chain([None]) {
+            assert_eq!(uf, TituloEleitor::generate_in(uf).uf());
+        }
+    }
+
+    #[test]
+    fn document() {
+        let a = TituloEleitor([0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6]);
+
+        assert_eq!(&[0, 0, 4, 3, 5, 6, 8, 7, 0, 9], a.base());
+        assert_eq!(&[0, 6], a.check_digits());
+        assert_eq!(
+            Ok([0, 6]),
+            TituloEleitor::compute_check_digits(&[0, 0, 4, 3, 5, 6, 8, 7, 0, 9])
+        );
+        assert_eq!(TituloEleitor::FORMATTED_LEN, format!("{a}").len());
+    }
+
+    #[test]
+    fn debug() {
+        let a = r#"TituloEleitor("0043 5687 0906")"#;
+        let b = TituloEleitor([0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6]);
+
+        assert_eq!(a, format!("{b:?}"));
+    }
+
+    #[test]
+    fn display() {
+        let a = "0043 5687 0906";
+        let b = TituloEleitor([0, 0, 4, 3, 5, 6, 8, 7, 0, 9, 0, 6]);
+
+        assert_eq!(a, format!("{b}"));
+    }
+
+    #[test]
+    fn from_str() {
+        let a = "0043 5687 0906".parse::<TituloEleitor>().unwrap();
+        let b = "004356870906".parse::<TituloEleitor>().unwrap();
+
+        assert_eq!(a, b);
+        assert_eq!(
+            "".parse::<TituloEleitor>(),
+            Err(ParseTituloEleitorError::Empty)
+        );
+        assert_eq!(
+            "0043-5687-0906".parse::<TituloEleitor>(),
+            Err(ParseTituloEleitorError::InvalidCharacter('-', 4))
+        );
+        assert_eq!(
+            "0043 5687 0907".parse::<TituloEleitor>(),
+            Err(ParseTituloEleitorError::InvalidNumber)
+        );
+        assert_eq!(
+            "0043 5687 2906".parse::<TituloEleitor>(),
+            Err(ParseTituloEleitorError::InvalidNumber)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        let titulo_str = "0043 5687 0906";
+        let titulo = TituloEleitor::from_str(titulo_str).unwrap();
+        serde_test::assert_tokens(&titulo, &[serde_test::Token::Str(titulo_str)]);
+    }
+}
@@ -0,0 +1,491 @@
+// uf.rs
+//
+// Copyright 2018 Ricardo Silva Veloso <ricvelozo@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT License
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+//
+// SPDX-License-Identifier: (MIT OR Apache-2.0)
+
+use core::convert::TryFrom;
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "serde")]
+use serde::*;
+
+/// An error which can be returned when parsing an [`Uf`].
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseUfError {
+    Empty,
+    InvalidUf,
+}
+
+impl fmt::Display for ParseUfError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use ParseUfError::*;
+        match self {
+            Empty => write!(f, "empty"),
+            InvalidUf => write!(f, "invalid UF"),
+        }
+    }
+}
+
+impl core::error::Error for ParseUfError {}
+
+/// A region of Brazil, as defined by IBGE.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Region {
+    Norte,
+    Nordeste,
+    Sudeste,
+    Sul,
+    CentroOeste,
+}
+
+impl Region {
+    /// Returns the name, as in `Centro-Oeste`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Region::Norte => "Norte",
+            Region::Nordeste => "Nordeste",
+            Region::Sudeste => "Sudeste",
+            Region::Sul => "Sul",
+            Region::CentroOeste => "Centro-Oeste",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// A Brazilian state (_unidade federativa_), including the Federal District. Parsing recognizes
+/// the two-letter abbreviation, in upper or lower case.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Uf {
+    /// Acre
+    Ac,
+    /// Alagoas
+    Al,
+    /// Amazonas
+    Am,
+    /// Amapá
+    Ap,
+    /// Bahia
+    Ba,
+    /// Ceará
+    Ce,
+    /// Distrito Federal
+    Df,
+    /// Espírito Santo
+    Es,
+    /// Goiás
+    Go,
+    /// Maranhão
+    Ma,
+    /// Minas Gerais
+    Mg,
+    /// Mato Grosso do Sul
+    Ms,
+    /// Mato Grosso
+    Mt,
+    /// Pará
+    Pa,
+    /// Paraíba
+    Pb,
+    /// Pernambuco
+    Pe,
+    /// Piauí
+    Pi,
+    /// Paraná
+    Pr,
+    /// Rio de Janeiro
+    Rj,
+    /// Rio Grande do Norte
+    Rn,
+    /// Rondônia
+    Ro,
+    /// Roraima
+    Rr,
+    /// Rio Grande do Sul
+    Rs,
+    /// Santa Catarina
+    Sc,
+    /// Sergipe
+    Se,
+    /// São Paulo
+    Sp,
+    /// Tocantins
+    To,
+}
+
+impl Uf {
+    /// All the states, in alphabetical order of abbreviation.
+    pub const ALL: [Uf; 27] = {
+        use Uf::*;
+        [
+            Ac, Al, Am, Ap, Ba, Ce, Df, Es, Go, Ma, Mg, Ms, Mt, Pa, Pb, Pe, Pi, Pr, Rj, Rn, Ro, Rr,
+            Rs, Sc, Se, Sp, To,
+        ]
+    };
+
+    /// Returns the two-letter abbreviation, in upper case.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Uf;
+    ///
+    /// assert_eq!("SP", Uf::Sp.abbreviation());
+    /// ```
+    pub const fn abbreviation(self) -> &'static str {
+        use Uf::*;
+        match self {
+            Ac => "AC",
+            Al => "AL",
+            Am => "AM",
+            Ap => "AP",
+            Ba => "BA",
+            Ce => "CE",
+            Df => "DF",
+            Es => "ES",
+            Go => "GO",
+            Ma => "MA",
+            Mg => "MG",
+            Ms => "MS",
+            Mt => "MT",
+            Pa => "PA",
+            Pb => "PB",
+            Pe => "PE",
+            Pi => "PI",
+            Pr => "PR",
+            Rj => "RJ",
+            Rn => "RN",
+            Ro => "RO",
+            Rr => "RR",
+            Rs => "RS",
+            Sc => "SC",
+            Se => "SE",
+            Sp => "SP",
+            To => "TO",
+        }
+    }
+
+    /// Returns the full name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Uf;
+    ///
+    /// assert_eq!("São Paulo", Uf::Sp.name());
+    /// ```
+    pub const fn name(self) -> &'static str {
+        use Uf::*;
+        match self {
+            Ac => "Acre",
+            Al => "Alagoas",
+            Am => "Amazonas",
+            Ap => "Amapá",
+            Ba => "Bahia",
+            Ce => "Ceará",
+            Df => "Distrito Federal",
+            Es => "Espírito Santo",
+            Go => "Goiás",
+            Ma => "Maranhão",
+            Mg => "Minas Gerais",
+            Ms => "Mato Grosso do Sul",
+            Mt => "Mato Grosso",
+            Pa => "Pará",
+            Pb => "Paraíba",
+            Pe => "Pernambuco",
+            Pi => "Piauí",
+            Pr => "Paraná",
+            Rj => "Rio de Janeiro",
+            Rn => "Rio Grande do Norte",
+            Ro => "Rondônia",
+            Rr => "Roraima",
+            Rs => "Rio Grande do Sul",
+            Sc => "Santa Catarina",
+            Se => "Sergipe",
+            Sp => "São Paulo",
+            To => "Tocantins",
+        }
+    }
+
+    /// Returns the numeric code used by IBGE, which is also the prefix of the municipality codes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Uf;
+    ///
+    /// assert_eq!(35, Uf::Sp.ibge_code());
+    /// ```
+    pub const fn ibge_code(self) -> u8 {
+        use Uf::*;
+        match self {
+            Ac => 12,
+            Al => 27,
+            Am => 13,
+            Ap => 16,
+            Ba => 29,
+            Ce => 23,
+            Df => 53,
+            Es => 32,
+            Go => 52,
+            Ma => 21,
+            Mg => 31,
+            Ms => 50,
+            Mt => 51,
+            Pa => 15,
+            Pb => 25,
+            Pe => 26,
+            Pi => 22,
+            Pr => 41,
+            Rj => 33,
+            Rn => 24,
+            Ro => 11,
+            Rr => 14,
+            Rs => 43,
+            Sc => 42,
+            Se => 28,
+            Sp => 35,
+            To => 17,
+        }
+    }
+
+    /// Returns the state with the IBGE code, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Uf;
+    ///
+    /// assert_eq!(Some(Uf::Sp), Uf::from_ibge_code(35));
+    /// assert_eq!(None, Uf::from_ibge_code(34));
+    /// ```
+    pub const fn from_ibge_code(code: u8) -> Option<Self> {
+        use Uf::*;
+        match code {
+            11 => Some(Ro),
+            12 => Some(Ac),
+            13 => Some(Am),
+            14 => Some(Rr),
+            15 => Some(Pa),
+            16 => Some(Ap),
+            17 => Some(To),
+            21 => Some(Ma),
+            22 => Some(Pi),
+            23 => Some(Ce),
+            24 => Some(Rn),
+            25 => Some(Pb),
+            26 => Some(Pe),
+            27 => Some(Al),
+            28 => Some(Se),
+            29 => Some(Ba),
+            31 => Some(Mg),
+            32 => Some(Es),
+            33 => Some(Rj),
+            35 => Some(Sp),
+            41 => Some(Pr),
+            42 => Some(Sc),
+            43 => Some(Rs),
+            50 => Some(Ms),
+            51 => Some(Mt),
+            52 => Some(Go),
+            53 => Some(Df),
+            _ => None,
+        }
+    }
+
+    /// Returns the region, derived from the first digit of the IBGE code.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::{Region, Uf};
+    ///
+    /// assert_eq!(Region::Sudeste, Uf::Sp.region());
+    /// ```
+    pub const fn region(self) -> Region {
+        match self.ibge_code() / 10 {
+            1 => Region::Norte,
+            2 => Region::Nordeste,
+            3 => Region::Sudeste,
+            4 => Region::Sul,
+            _ => Region::CentroOeste,
+        }
+    }
+
+    /// Returns the ISO 3166-2 code, as in `BR-SP`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use brids::Uf;
+    ///
+    /// assert_eq!("BR-SP", Uf::Sp.iso_3166_2());
+    /// ```
+    pub const fn iso_3166_2(self) -> &'static str {
+        use Uf::*;
+        match self {
+            Ac => "BR-AC",
+            Al => "BR-AL",
+            Am => "BR-AM",
+            Ap => "BR-AP",
+            Ba => "BR-BA",
+            Ce => "BR-CE",
+            Df => "BR-DF",
+            Es => "BR-ES",
+            Go => "BR-GO",
+            Ma => "BR-MA",
+            Mg => "BR-MG",
+            Ms => "BR-MS",
+            Mt => "BR-MT",
+            Pa => "BR-PA",
+            Pb => "BR-PB",
+            Pe => "BR-PE",
+            Pi => "BR-PI",
+            Pr => "BR-PR",
+            Rj => "BR-RJ",
+            Rn => "BR-RN",
+            Ro => "BR-RO",
+            Rr => "BR-RR",
+            Rs => "BR-RS",
+            Sc => "BR-SC",
+            Se => "BR-SE",
+            Sp => "BR-SP",
+            To => "BR-TO",
+        }
+    }
+}
+
+impl fmt::Display for Uf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+impl FromStr for Uf {
+    type Err = ParseUfError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseUfError::Empty);
+        }
+
+        Self::ALL
+            .into_iter()
+            .find(|uf| uf.abbreviation().eq_ignore_ascii_case(s))
+            .ok_or(ParseUfError::InvalidUf)
+    }
+}
+
+impl TryFrom<u8> for Uf {
+    type Error = ParseUfError;
+
+    /// Converts an IBGE code.
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_ibge_code(value).ok_or(ParseUfError::InvalidUf)
+    }
+}
+
+impl From<Uf> for u8 {
+    /// Converts to the IBGE code.
+    #[inline]
+    fn from(uf: Uf) -> u8 {
+        uf.ibge_code()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Uf {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.abbreviation())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Uf {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct UfVisitor;
+
+        impl de::Visitor<'_> for UfVisitor {
+            type Value = Uf;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a UF abbreviation")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Uf, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(UfVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(feature = "std"))]
+    use alloc::format;
+
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!("DF", format!("{}", Uf::Df));
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(Ok(Uf::Sp), "SP".parse::<Uf>());
+        assert_eq!(Ok(Uf::Rj), "rj".parse::<Uf>());
+        assert_eq!(Err(ParseUfError::Empty), "".parse::<Uf>());
+        assert_eq!(Err(ParseUfError::InvalidUf), "ZZ".parse::<Uf>());
+        assert_eq!(Err(ParseUfError::InvalidUf), "SPA".parse::<Uf>());
+
+        for uf in Uf::ALL {
+            assert_eq!(Ok(uf), uf.abbreviation().parse());
+        }
+    }
+
+    #[test]
+    fn ibge_code() {
+        assert_eq!(53, Uf::Df.ibge_code());
+        assert_eq!(Ok(Uf::Rs), Uf::try_from(43));
+        assert_eq!(Err(ParseUfError::InvalidUf), Uf::try_from(0));
+
+        for uf in Uf::ALL {
+            assert_eq!(Some(uf), Uf::from_ibge_code(uf.ibge_code()));
+        }
+    }
+
+    #[test]
+    fn metadata() {
+        assert_eq!("Espírito Santo", Uf::Es.name());
+        assert_eq!("BR-DF", Uf::Df.iso_3166_2());
+        assert_eq!(Region::Norte, Uf::To.region());
+        assert_eq!(Region::Nordeste, Uf::Ma.region());
+        assert_eq!(Region::Sul, Uf::Pr.region());
+        assert_eq!(Region::CentroOeste, Uf::Df.region());
+        assert_eq!("Centro-Oeste", format!("{}", Region::CentroOeste));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize() {
+        serde_test::assert_tokens(&Uf::Sp, &[serde_test::Token::Str("SP")]);
+    }
+}